@@ -0,0 +1,230 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{parse_day, AnyResult, Solution};
+
+/// The median, fastest and slowest of a set of timings of the same stage.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Stats {
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    /// Summarises `samples`, which must not be empty.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "Tried to summarise zero timings.");
+
+        samples.sort_unstable();
+
+        let middle = samples.len() / 2;
+
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Self {
+            median_ns: median.as_nanos() as u64,
+            min_ns: samples[0].as_nanos() as u64,
+            max_ns: samples[samples.len() - 1].as_nanos() as u64,
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(self.min_ns)
+    }
+
+    pub fn max(&self) -> Duration {
+        Duration::from_nanos(self.max_ns)
+    }
+}
+
+/// One of the separately timed steps of solving a day.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub const ALL: [Self; 3] = [Self::Parse, Self::Part1, Self::Part2];
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Padded so that stages line up when printed in a table.
+        f.pad(match self {
+            Self::Parse => "parse",
+            Self::Part1 => "part 1",
+            Self::Part2 => "part 2",
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DayTimings {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    /// Missing for a day without a second part.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Stats>,
+}
+
+impl DayTimings {
+    /// Returns the timings of the given stage, or `None` if the day doesn't have it.
+    pub fn stage(&self, stage: Stage) -> Option<&Stats> {
+        match stage {
+            Stage::Parse => Some(&self.parse),
+            Stage::Part1 => Some(&self.part1),
+            Stage::Part2 => self.part2.as_ref(),
+        }
+    }
+}
+
+/// Every day benchmarked by a single invocation, along with when it happened.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BenchRun {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub iterations: usize,
+    pub days: Vec<DayTimings>,
+}
+
+impl BenchRun {
+    pub fn new(iterations: usize) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            timestamp,
+            iterations,
+            days: Vec::new(),
+        }
+    }
+}
+
+/// Every benchmark run which has been saved, oldest first.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct History {
+    pub runs: Vec<BenchRun>,
+}
+
+impl History {
+    /// Returns the path the history is saved to when no other path is given.
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("bench_history.json")
+    }
+
+    /// Loads the history at `path`, or an empty history if nothing has been saved there yet.
+    pub fn load(path: &Path) -> AnyResult<Self> {
+        match fs::read_to_string(path) {
+            Ok(json) => Ok(serde_json::from_str(&json)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(Box::new(err)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> AnyResult {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+
+        Ok(())
+    }
+
+    /// Returns the timings of the most recent run which benchmarked the given day.
+    pub fn latest(&self, day: u8) -> Option<&DayTimings> {
+        self.runs
+            .iter()
+            .rev()
+            .find_map(|run| run.days.iter().find(|timings| timings.day == day))
+    }
+}
+
+/// A stage whose median time has grown by more than the allowed threshold since it was
+/// last benchmarked.
+#[derive(Debug)]
+pub struct Regression {
+    pub stage: Stage,
+    pub previous: Stats,
+    pub current: Stats,
+}
+
+impl Regression {
+    /// Returns how much slower the current median is, as a fraction of the previous median,
+    /// which must not be zero.
+    pub fn slowdown(&self) -> f64 {
+        self.current.median_ns as f64 / self.previous.median_ns as f64 - 1.0
+    }
+}
+
+/// Compares `current` against the previous timings of the same day, returning every stage
+/// which is more than `threshold` (as a fraction, so `0.1` is 10%) slower.
+///
+/// Stages which are missing from either timings can't be compared, so are skipped.
+///
+/// Stages whose previous median is zero, such as from a hand-edited history, have nothing to be
+/// a fraction of, so are never reported.
+pub fn find_regressions(
+    previous: &DayTimings,
+    current: &DayTimings,
+    threshold: f64,
+) -> Vec<Regression> {
+    Stage::ALL
+        .into_iter()
+        .filter_map(|stage| {
+            Some(Regression {
+                stage,
+                previous: *previous.stage(stage)?,
+                current: *current.stage(stage)?,
+            })
+        })
+        .filter(|regression| regression.previous.median_ns > 0 && regression.slowdown() > threshold)
+        .collect()
+}
+
+/// Times parsing and each part of `S` separately, `iterations` times over.
+pub fn bench<S: Solution>(day: u8, input: &str, iterations: usize) -> AnyResult<DayTimings> {
+    let iterations = iterations.max(1);
+
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut part1_samples = Vec::with_capacity(iterations);
+    let mut part2_samples = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = std::hint::black_box(parse_day::<S>(day, input)?);
+        parse_samples.push(start.elapsed());
+
+        let start = Instant::now();
+        std::hint::black_box(S::part1(&parsed)?);
+        part1_samples.push(start.elapsed());
+
+        if S::HAS_PART2 {
+            let start = Instant::now();
+            std::hint::black_box(S::part2(&parsed)?);
+            part2_samples.push(start.elapsed());
+        }
+    }
+
+    Ok(DayTimings {
+        day,
+        parse: Stats::from_samples(&mut parse_samples),
+        part1: Stats::from_samples(&mut part1_samples),
+        part2: S::HAS_PART2.then(|| Stats::from_samples(&mut part2_samples)),
+    })
+}
@@ -0,0 +1,786 @@
+use std::{fs, path::PathBuf, process::ExitCode, str::FromStr, time::Duration};
+
+use adventofcode_2022::{
+    bench::{find_regressions, BenchRun, History, Stage},
+    days::{
+        day12::{Heightmap, Traversal},
+        day14::parse_cave,
+        day15::{self, tuning_frequency, BeaconExclusionZone},
+        day17::{self, parse_jets, Simulation},
+        day18::parse_droplet,
+        day19::parse_blueprints,
+        day7::{human_size, parse_file_system},
+    },
+    find_day,
+    input::InputNotFound,
+    parse::ParseError,
+    AnyResult, CustomError, Day, Part, DAYS,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solution for a single day, or for every day.
+    Run {
+        /// The day to run, or `all`.
+        day: DaySelection,
+
+        /// Only run the given part of the puzzle.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Reads the puzzle input from this path instead of looking in `$AOC_INPUT_DIR`, the user
+        /// cache and then the bundled input.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Times parsing and each part of a single day, or of every day, and compares the timings
+    /// against the previous run.
+    Bench {
+        /// The day to benchmark, or `all`.
+        day: DaySelection,
+
+        /// How many times to time each stage.
+        #[arg(long, default_value_t = 10)]
+        iterations: usize,
+
+        /// Where to read and save the history of previous runs.
+        #[arg(long)]
+        history: Option<PathBuf>,
+
+        /// How much slower (in percent) a stage's median time can get before it is flagged as a
+        /// regression.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Compares against the history without adding this run to it.
+        #[arg(long)]
+        no_save: bool,
+
+        /// Reads the puzzle input from this path instead of looking in `$AOC_INPUT_DIR`, the user
+        /// cache and then the bundled input.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Prints the order to build robots in which opens the most geodes with each of day 19's
+    /// blueprints.
+    ExplainBlueprints {
+        /// Only explain the blueprint with this ID.
+        #[arg(long)]
+        blueprint: Option<u32>,
+
+        /// How many minutes there are to open geodes in.
+        #[arg(long, default_value_t = 24)]
+        minutes: u32,
+
+        /// Reads the puzzle input from this path instead of looking in `$AOC_INPUT_DIR`, the user
+        /// cache and then the bundled input.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Draws the tower of rocks in day 17's chamber, optionally replaying every push and fall of
+    /// some of the rocks on the way.
+    Tower {
+        /// How many rocks to drop before drawing the tower.
+        #[arg(long, default_value_t = 2022)]
+        rocks: usize,
+
+        /// Shows each step taken by every rock from this one onwards, counting from 1.
+        #[arg(long)]
+        replay_from: Option<usize>,
+
+        /// Only draws this many rows from the top of the tower.
+        #[arg(long)]
+        rows: Option<usize>,
+
+        /// How to draw the tower once the rocks have fallen.
+        #[arg(long, value_enum, default_value_t = ImageFormat::Text)]
+        format: ImageFormat,
+
+        /// Writes the tower to this path instead of printing it.
+        #[arg(long)]
+        output: Option<PathBuf>,
+
+        /// Reads the puzzle input from this path instead of looking in `$AOC_INPUT_DIR`, the user
+        /// cache and then the bundled input.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Solves day 15 for any row and search area, such as those in the puzzle's example.
+    DistressBeacon {
+        /// The row to count the positions which can't contain a beacon in.
+        #[arg(long, default_value_t = day15::PUZZLE_ROW)]
+        row: i32,
+
+        /// The largest either coordinate of the distress beacon can be.
+        #[arg(long, default_value_t = day15::PUZZLE_BOUND)]
+        bound: i32,
+
+        /// Reads the puzzle input from this path instead of looking in `$AOC_INPUT_DIR`, the user
+        /// cache and then the bundled input.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Draws the shortest route up the hill on day 12's heightmap.
+    HillRoute {
+        /// Searches down from the end to the nearest of the lowest squares, as in part 2, then
+        /// draws the route up from there.
+        #[arg(long)]
+        reverse: bool,
+
+        /// Shades the heightmap by elevation with ANSI colours, and highlights the start.
+        #[arg(long)]
+        shade: bool,
+
+        /// How to draw the route.
+        #[arg(long, value_enum, default_value_t = ImageFormat::Text)]
+        format: ImageFormat,
+
+        /// Writes the route to this path instead of printing it.
+        #[arg(long)]
+        output: Option<PathBuf>,
+
+        /// Reads the puzzle input from this path instead of looking in `$AOC_INPUT_DIR`, the user
+        /// cache and then the bundled input.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Pours sand into day 14's cave until it stops coming to rest, then draws the cave.
+    Cave {
+        /// Adds the floor two below the lowest rock, as in part 2.
+        #[arg(long)]
+        floor: bool,
+
+        /// How to draw the cave once the sand has settled.
+        #[arg(long, value_enum, default_value_t = ImageFormat::Text)]
+        format: ImageFormat,
+
+        /// Writes the cave to this path instead of printing it.
+        #[arg(long)]
+        output: Option<PathBuf>,
+
+        /// Reads the puzzle input from this path instead of looking in `$AOC_INPUT_DIR`, the user
+        /// cache and then the bundled input.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Reports the surface area of day 18's lava droplet and the air pockets trapped inside it,
+    /// optionally exporting its outside as a mesh.
+    Droplet {
+        /// Writes a mesh of the faces of the droplet which can be reached from outside it to
+        /// this path.
+        #[arg(long)]
+        output: Option<PathBuf>,
+
+        /// The file format to write the mesh in.
+        #[arg(long, value_enum, default_value_t = MeshFormat::Obj)]
+        format: MeshFormat,
+
+        /// Reads the puzzle input from this path instead of looking in `$AOC_INPUT_DIR`, the user
+        /// cache and then the bundled input.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Explores the file system pieced together from day 7's terminal output.
+    Filesystem {
+        #[command(subcommand)]
+        query: FilesystemQuery,
+
+        /// Reads the puzzle input from this path instead of looking in `$AOC_INPUT_DIR`, the user
+        /// cache and then the bundled input.
+        #[arg(long, global = true)]
+        input: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum FilesystemQuery {
+    /// Draws every directory and file like `tree`, with their sizes.
+    Tree,
+    /// Lists the size of each directory like `du -h`, biggest first.
+    Du {
+        /// Only lists directories at most this many levels below the root.
+        #[arg(long)]
+        max_depth: Option<usize>,
+    },
+    /// Lists the path and size of everything matching a glob pattern, such as `/a/*/i` or
+    /// `**/*.txt`.
+    Find { pattern: String },
+    /// Reports any `ls` of a directory which was already listed, and any `cd` into a directory
+    /// no `ls` had shown.
+    Check,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum MeshFormat {
+    /// A Wavefront OBJ file.
+    Obj,
+    /// An ASCII PLY file.
+    Ply,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ImageFormat {
+    /// The puzzle's own notation, one character per cell.
+    Text,
+    /// A binary PPM image, one pixel per cell.
+    Ppm,
+}
+
+#[derive(Clone, Copy)]
+enum DaySelection {
+    All,
+    Single(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = CustomError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+
+        let number: u8 = s.parse().map_err(|_| CustomError {
+            msg: format!("`{s}` is neither a day number nor `all`.").into(),
+        })?;
+
+        find_day(number).ok_or(CustomError {
+            msg: format!("Day {number} has not been solved yet.").into(),
+        })?;
+
+        Ok(Self::Single(number))
+    }
+}
+
+fn main() -> ExitCode {
+    // Errors are printed with `Display` rather than `Debug`, so that multi-line messages such as
+    // the locations an input was looked for in stay readable.
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(Cli { command }: Cli) -> AnyResult {
+    match command {
+        Command::Run { day, part, input } => {
+            let parts = match part {
+                Some(1) => vec![Part::One],
+                Some(_) => vec![Part::Two],
+                None => vec![Part::One, Part::Two],
+            };
+
+            match day {
+                DaySelection::All => {
+                    check_no_input(&input)?;
+
+                    let num_failures: usize = DAYS
+                        .iter()
+                        .map(|day| run_day(day, &parts, None, true))
+                        .sum();
+
+                    if num_failures > 0 {
+                        return Err(Box::new(CustomError {
+                            msg: format!("{num_failures} part(s) failed to produce an answer.")
+                                .into(),
+                        }));
+                    }
+                }
+                DaySelection::Single(number) => {
+                    // `DaySelection::from_str` has already checked that this day exists.
+                    let day = find_day(number).unwrap();
+
+                    if run_day(day, &parts, input, false) > 0 {
+                        return Err(Box::new(CustomError {
+                            msg: format!("Day {number} failed to produce an answer.").into(),
+                        }));
+                    }
+                }
+            }
+        }
+        Command::Bench {
+            day,
+            iterations,
+            history,
+            threshold,
+            no_save,
+            input,
+        } => {
+            // Only days which weren't asked for by name are skipped when they have no input.
+            let skip_missing = matches!(day, DaySelection::All);
+            let days: Vec<&Day> = match day {
+                DaySelection::All => {
+                    check_no_input(&input)?;
+
+                    DAYS.iter().collect()
+                }
+                // `DaySelection::from_str` has already checked that this day exists.
+                DaySelection::Single(number) => vec![find_day(number).unwrap()],
+            };
+
+            let history_path = history.unwrap_or_else(History::default_path);
+            let mut history = History::load(&history_path)?;
+            let mut run = BenchRun::new(iterations);
+            let mut num_failures = 0;
+            let mut num_regressions = 0;
+
+            for day in days {
+                // A day without an input is skipped rather than stopping every later day from
+                // being timed.
+                let input = match day.read_input(input.as_deref()) {
+                    Ok(input) => input,
+                    Err(err) if skip_missing && err.is::<InputNotFound>() => {
+                        println!("Day {} skipped (no input)", day.number);
+                        continue;
+                    }
+                    Err(err) => {
+                        eprintln!("Day {} failed: {err}", day.number);
+                        num_failures += 1;
+                        continue;
+                    }
+                };
+
+                let timings = match day.bench(&input, iterations) {
+                    Ok(timings) => timings,
+                    Err(err) => {
+                        eprintln!("Day {} failed: {err}", day.number);
+                        num_failures += 1;
+                        continue;
+                    }
+                };
+
+                let regressions = history
+                    .latest(day.number)
+                    .map(|previous| find_regressions(previous, &timings, threshold / 100.0))
+                    .unwrap_or_default();
+
+                println!("Day {}", day.number);
+
+                for stage in Stage::ALL {
+                    // Days without a second part aren't timed for it.
+                    let Some(stats) = timings.stage(stage) else {
+                        continue;
+                    };
+
+                    print!(
+                        "  {stage:<6}  median {:>10}  min {:>10}  max {:>10}",
+                        format_duration(stats.median()),
+                        format_duration(stats.min()),
+                        format_duration(stats.max()),
+                    );
+
+                    match regressions
+                        .iter()
+                        .find(|regression| regression.stage == stage)
+                    {
+                        Some(regression) => {
+                            println!(
+                                "  REGRESSION +{:.1}% (was {})",
+                                regression.slowdown() * 100.0,
+                                format_duration(regression.previous.median()),
+                            );
+                        }
+                        None => println!(),
+                    }
+                }
+
+                num_regressions += regressions.len();
+                run.days.push(timings);
+            }
+
+            if !no_save && !run.days.is_empty() {
+                history.runs.push(run);
+                history.save(&history_path)?;
+            }
+
+            if num_failures > 0 {
+                return Err(Box::new(CustomError {
+                    msg: format!("{num_failures} day(s) failed to run.").into(),
+                }));
+            }
+
+            if num_regressions > 0 {
+                return Err(Box::new(CustomError {
+                    msg: format!("{num_regressions} stage(s) regressed by more than {threshold}%.")
+                        .into(),
+                }));
+            }
+        }
+        Command::ExplainBlueprints {
+            blueprint,
+            minutes,
+            input,
+        } => {
+            // Day 19 is always solved, so it can always be found.
+            let day = find_day(19).unwrap();
+
+            let input = day.read_input(input.as_deref())?;
+
+            let blueprints = parse_blueprints(&input).map_err(|err| err.with_day(day.number))?;
+
+            let mut num_explained = 0;
+
+            for plan in blueprints
+                .iter()
+                .filter(|candidate| blueprint.is_none_or(|id| candidate.id == id))
+                .map(|blueprint| blueprint.plan(minutes))
+            {
+                if num_explained > 0 {
+                    println!();
+                }
+
+                println!("{plan}");
+                num_explained += 1;
+            }
+
+            if num_explained == 0 {
+                return Err(Box::new(CustomError {
+                    msg: "No blueprint has the given ID.".into(),
+                }));
+            }
+        }
+        Command::Tower {
+            rocks,
+            replay_from,
+            rows,
+            format,
+            output,
+            input,
+        } => {
+            if replay_from.is_some_and(|first| !(1..=rocks).contains(&first)) {
+                return Err(Box::new(CustomError {
+                    msg: "`--replay-from` must be between 1 and the number of rocks.".into(),
+                }));
+            }
+
+            check_ppm_output(format, &output)?;
+
+            // Day 17 is always solved, so it can always be found.
+            let day = find_day(17).unwrap();
+
+            let input = day.read_input(input.as_deref())?;
+
+            let jets = parse_jets(&input).map_err(|err| err.with_day(day.number))?;
+
+            let mut simulation = Simulation::new(&jets);
+            let num_skipped = replay_from.map_or(rocks, |first| first - 1);
+
+            simulation.run_with_limit(num_skipped);
+
+            for _ in num_skipped..rocks {
+                simulation.drop_rock_with(|simulation, rock, event| {
+                    println!("{event}\n{}", simulation.chamber(Some(rock), rows));
+                });
+            }
+
+            let chamber = simulation.chamber(None, rows);
+
+            export(format, output, chamber.to_string(), || {
+                chamber.cells.to_ppm(|cell| match cell {
+                    day17::Cell::Air => [24, 24, 32],
+                    day17::Cell::FallingRock => [230, 140, 40],
+                    day17::Cell::Rock => [150, 150, 150],
+                })
+            })?;
+        }
+        Command::DistressBeacon { row, bound, input } => {
+            // Day 15 is always solved, so it can always be found.
+            let day = find_day(15).unwrap();
+
+            let input = day.read_input(input.as_deref())?;
+
+            let zone: BeaconExclusionZone = input
+                .parse()
+                .map_err(|err: ParseError| err.with_day(day.number))?;
+
+            println!(
+                "Positions in row {row} which can't contain a beacon: {}",
+                zone.num_without_beacon(row)
+            );
+
+            let beacon = zone.distress_beacon(bound).ok_or(CustomError {
+                msg: "No position was out of range of all sensors.".into(),
+            })?;
+
+            println!(
+                "Distress beacon: x={}, y={}, tuning frequency {}",
+                beacon.x,
+                beacon.y,
+                tuning_frequency(beacon)
+            );
+        }
+        Command::HillRoute {
+            reverse,
+            shade,
+            format,
+            output,
+            input,
+        } => {
+            check_ppm_output(format, &output)?;
+
+            // Day 12 is always solved, so it can always be found.
+            let day = find_day(12).unwrap();
+
+            let input = day.read_input(input.as_deref())?;
+
+            let heightmap: Heightmap = input
+                .parse()
+                .map_err(|err: ParseError| err.with_day(day.number))?;
+
+            let traversal = if reverse {
+                Traversal::Reverse
+            } else {
+                Traversal::Forward
+            };
+
+            let route = heightmap.route(traversal).ok_or(CustomError {
+                msg: "No route to the destination exists.".into(),
+            })?;
+
+            let (x, y) = route.start();
+
+            eprintln!("The route from x={x}, y={y} takes {} steps.", route.steps());
+
+            let text = if shade {
+                route.to_ansi()
+            } else {
+                format!("{route}\n")
+            };
+
+            export(format, output, text, || route.to_ppm())?;
+        }
+        Command::Cave {
+            floor,
+            format,
+            output,
+            input,
+        } => {
+            check_ppm_output(format, &output)?;
+
+            // Day 14 is always solved, so it can always be found.
+            let day = find_day(14).unwrap();
+
+            let input = day.read_input(input.as_deref())?;
+
+            let mut cave = parse_cave(&input).map_err(|err| err.with_day(day.number))?;
+
+            if floor {
+                cave = cave.with_floor();
+            }
+
+            let num_resting = cave.pour_sand();
+            let picture = cave.picture();
+
+            eprintln!("{num_resting} units of sand came to rest.");
+
+            export(format, output, format!("{picture}\n"), || {
+                picture.to_ppm(|tile| match tile {
+                    '#' => [120, 110, 100],
+                    'o' => [230, 200, 120],
+                    '+' => [255, 60, 60],
+                    _ => [24, 24, 32],
+                })
+            })?;
+        }
+        Command::Droplet {
+            output,
+            format,
+            input,
+        } => {
+            // Day 18 is always solved, so it can always be found.
+            let day = find_day(18).unwrap();
+
+            let input = day.read_input(input.as_deref())?;
+
+            let droplet = parse_droplet(&input).map_err(|err| err.with_day(day.number))?;
+            let pockets = droplet.pockets();
+
+            println!("Surface area: {}", droplet.surface_area());
+            println!("Exterior surface area: {}", droplet.exterior_surface_area());
+            println!("Air pockets trapped inside: {}", pockets.len());
+
+            for pocket in pockets {
+                let (min, max) = (pocket.bounds.min, pocket.bounds.max);
+
+                println!(
+                    "  {:>4} cube(s) from {},{},{} to {},{},{}",
+                    pocket.size, min.x, min.y, min.z, max.x, max.y, max.z
+                );
+            }
+
+            if let Some(output) = output {
+                let mesh = droplet.exterior_mesh();
+
+                fs::write(
+                    output,
+                    match format {
+                        MeshFormat::Obj => mesh.to_obj(),
+                        MeshFormat::Ply => mesh.to_ply(),
+                    },
+                )?;
+            }
+        }
+        Command::Filesystem { query, input } => {
+            // Day 7 is always solved, so it can always be found.
+            let day = find_day(7).unwrap();
+
+            let input = day.read_input(input.as_deref())?;
+
+            let file_system = parse_file_system(&input).map_err(|err| err.with_day(day.number))?;
+            let problems = file_system.problems();
+
+            // The other queries work around any problems, but shouldn't hide them.
+            if !matches!(query, FilesystemQuery::Check) {
+                for problem in problems {
+                    eprintln!("Warning: {problem}");
+                }
+            }
+
+            match query {
+                FilesystemQuery::Tree => print!("{file_system}"),
+                FilesystemQuery::Du { max_depth } => {
+                    for (path, size) in file_system.disk_usage(max_depth) {
+                        println!("{:<7} {path}", human_size(size));
+                    }
+                }
+                FilesystemQuery::Find { pattern } => {
+                    let matches = file_system.glob(&pattern);
+
+                    if matches.is_empty() {
+                        return Err(Box::new(CustomError {
+                            msg: format!("Nothing matches `{pattern}`.").into(),
+                        }));
+                    }
+
+                    for id in matches {
+                        println!(
+                            "{:<7} {}",
+                            human_size(file_system.entry(id).size),
+                            file_system.path(id)
+                        );
+                    }
+                }
+                FilesystemQuery::Check => {
+                    for problem in problems {
+                        println!("{problem}");
+                    }
+
+                    if !problems.is_empty() {
+                        return Err(Box::new(CustomError {
+                            msg: format!("{} problem(s) were found.", problems.len()).into(),
+                        }));
+                    }
+
+                    println!("No problems were found.");
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn check_ppm_output(format: ImageFormat, output: &Option<PathBuf>) -> AnyResult {
+    if matches!(format, ImageFormat::Ppm) && output.is_none() {
+        return Err(Box::new(CustomError {
+            msg: "PPM images can only be written to a file given with `--output`.".into(),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Prints `text`, or writes it to `output` if given, or writes the image from `to_ppm` there
+/// instead when a PPM image was asked for.
+fn export(
+    format: ImageFormat,
+    output: Option<PathBuf>,
+    text: String,
+    to_ppm: impl FnOnce() -> Vec<u8>,
+) -> AnyResult {
+    match (format, output) {
+        (ImageFormat::Text, None) => print!("{text}"),
+        (ImageFormat::Text, Some(output)) => fs::write(output, text)?,
+        (ImageFormat::Ppm, Some(output)) => fs::write(output, to_ppm())?,
+        (ImageFormat::Ppm, None) => check_ppm_output(format, &None)?,
+    }
+
+    Ok(())
+}
+
+fn check_no_input(input: &Option<PathBuf>) -> AnyResult {
+    if input.is_some() {
+        return Err(Box::new(CustomError {
+            msg: "`--input` can only be used with a single day.".into(),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Formats a duration with a unit suited to its size, e.g. `812ns`, `41.3µs` or `2.07s`.
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+
+    if nanos < 1e3 {
+        format!("{nanos}ns")
+    } else if nanos < 1e6 {
+        format!("{:.1}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.1}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
+
+/// Prints the answer to each of `parts`, returning the number of parts which failed. Parts the
+/// day's puzzle doesn't have are skipped.
+///
+/// Every part fails if the day's input can't be read, rather than stopping any other days from
+/// running. With `skip_missing`, a day which has no input at all is skipped instead, as inputs
+/// aren't bundled for every day.
+fn run_day(day: &Day, parts: &[Part], input_path: Option<PathBuf>, skip_missing: bool) -> usize {
+    let input = match day.read_input(input_path.as_deref()) {
+        Ok(input) => input,
+        Err(err) if skip_missing && err.is::<InputNotFound>() => {
+            println!("Day {} skipped (no input)", day.number);
+            return 0;
+        }
+        Err(err) => {
+            eprintln!("Day {} failed: {err}", day.number);
+            return parts.len();
+        }
+    };
+
+    println!("Day {}", day.number);
+
+    let mut num_failures = 0;
+
+    for &part in parts {
+        if !day.parts().contains(&part) {
+            println!("Part {part} skipped (no puzzle)");
+            continue;
+        }
+
+        match day.solve(&input, part) {
+            Ok(answer) => println!("Part {part} answer = {answer}"),
+            Err(err) => {
+                eprintln!("Part {part} failed: {err}");
+                num_failures += 1;
+            }
+        }
+    }
+
+    num_failures
+}
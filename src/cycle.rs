@@ -0,0 +1,86 @@
+use std::{
+    hash::Hash,
+    ops::{Add, Mul, Sub},
+};
+
+use crate::hash::FastHashMap;
+
+/// A stream of states which, after some number of steps, starts repeating itself, along with a
+/// metric measured after every step up to the end of the first repeat.
+#[derive(Clone, Debug)]
+pub struct Cycle<M> {
+    start: usize,
+    length: usize,
+    /// The metric after each number of steps, from none up to and including `start + length`.
+    metrics: Vec<M>,
+}
+
+impl<M> Cycle<M> {
+    /// Returns the number of steps taken before the states start repeating.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the number of steps it takes for the states to come back around.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+}
+
+impl<M> Cycle<M>
+where
+    M: Copy + Add<Output = M> + Sub<Output = M> + Mul<Output = M> + TryFrom<u64>,
+{
+    /// Returns the metric after `steps` steps, which may be far beyond those actually taken, on
+    /// the basis that it changes by the same amount every time around the cycle.
+    ///
+    /// Returns `None` if the number of times around the cycle doesn't fit in `M`.
+    pub fn extrapolate(&self, steps: u64) -> Option<M> {
+        if let Some(&metric) = usize::try_from(steps)
+            .ok()
+            .and_then(|steps| self.metrics.get(steps))
+        {
+            return Some(metric);
+        }
+
+        let (start, length) = (self.start as u64, self.length as u64);
+
+        let cycles = M::try_from((steps - start) / length).ok()?;
+        let offset = ((steps - start) % length) as usize;
+
+        let per_cycle = self.metrics[self.start + self.length] - self.metrics[self.start];
+
+        Some(self.metrics[self.start + offset] + per_cycle * cycles)
+    }
+}
+
+/// Steps through `states` until one has the same fingerprint as a state before it, measuring
+/// `metric` along the way. The first state is the one before any steps have been taken.
+///
+/// Fingerprints must capture everything which decides the states that follow, as the stream is
+/// assumed to repeat from the moment one does. Returns `None` if `states` runs out first.
+pub fn find_cycle<S, K, M>(
+    states: impl IntoIterator<Item = S>,
+    mut fingerprint: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> M,
+) -> Option<Cycle<M>>
+where
+    K: Eq + Hash,
+{
+    let mut first_seen = FastHashMap::default();
+    let mut metrics = Vec::new();
+
+    for (step, state) in states.into_iter().enumerate() {
+        metrics.push(metric(&state));
+
+        if let Some(start) = first_seen.insert(fingerprint(&state), step) {
+            return Some(Cycle {
+                start,
+                length: step - start,
+                metrics,
+            });
+        }
+    }
+
+    None
+}
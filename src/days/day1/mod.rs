@@ -0,0 +1,58 @@
+use crate::{
+    parse::{parse_number, ParseError},
+    AnyResult, CustomError, Solution,
+};
+
+pub struct Day1;
+
+/// Returns the total calories an elf is holding, or an error
+/// if he was holding a non-numerical (or negative) calorie snack.
+fn elf_to_calories(input: &str, elf: &str) -> Result<u32, ParseError> {
+    elf.lines()
+        .map(|line| parse_number::<u32>(input, line))
+        .sum()
+}
+
+impl Solution for Day1 {
+    type Parsed<'a> = Vec<u32>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(input
+            .split("\n\n")
+            .map(|elf| elf_to_calories(input, elf))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(elves: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let max_calories = elves.iter().copied().max().ok_or(CustomError {
+            msg: "There were no elves in the input.".into(),
+        })?;
+
+        Ok(max_calories)
+    }
+
+    fn part2(elves: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let maxima = elves
+            .iter()
+            // Iterate through all elves, storing the 3 highest values we've seen so far
+            .fold([0, 0, 0], |mut maxima, &calories| {
+                if calories > maxima[2] {
+                    maxima[0] = maxima[1];
+                    maxima[1] = maxima[2];
+                    maxima[2] = calories;
+                } else if calories > maxima[1] {
+                    maxima[0] = maxima[1];
+                    maxima[1] = calories;
+                } else if calories > maxima[0] {
+                    maxima[0] = calories;
+                }
+
+                maxima
+            });
+
+        let top_three_calorie_sum: u32 = maxima.into_iter().sum();
+
+        Ok(top_three_calorie_sum)
+    }
+}
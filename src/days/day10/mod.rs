@@ -0,0 +1,151 @@
+use std::str::FromStr;
+
+use crate::{
+    parse::{parse_lines, parse_number, split_once, ParseError, ParseErrorKind},
+    Answer, AnyResult, Solution,
+};
+
+pub struct Day10;
+
+pub enum Instruction {
+    AddX(i32),
+    Noop,
+}
+
+struct Program {
+    cycle: usize,
+    register: i32,
+}
+
+impl Program {
+    fn get_pixel(&self) -> char {
+        if (self.cycle as i32 % 40).abs_diff(self.register) <= 1 {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "noop" {
+            return Ok(Self::Noop);
+        }
+
+        let (instruction, value) = split_once(s, s, " ")?;
+
+        if instruction != "addx" {
+            return Err(ParseError::new(
+                ParseErrorKind::Unexpected("either `noop` or `addx`"),
+                s,
+                instruction,
+            ));
+        }
+
+        let value: i32 = parse_number(s, value)?;
+
+        Ok(Self::AddX(value))
+    }
+}
+
+impl Solution for Day10 {
+    type Parsed<'a> = Vec<Instruction>;
+    type Answer = Answer;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(parse_lines(input, str::parse)?)
+    }
+
+    fn part1(instructions: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let mut program = Program {
+            cycle: 1,
+            register: 1,
+        };
+
+        let signal_strength_sum: i32 = instructions
+            .iter()
+            .filter_map(|instruction| {
+                let mut signal_strength: Option<i32> = None;
+
+                match instruction {
+                    Instruction::AddX(value) => {
+                        for _ in 0..2 {
+                            if program.cycle % 40 == 20 {
+                                signal_strength = Some(program.cycle as i32 * program.register);
+                            }
+                            program.cycle += 1;
+                        }
+
+                        program.register += value;
+                    }
+                    Instruction::Noop => {
+                        if program.cycle % 40 == 20 {
+                            signal_strength = Some(program.cycle as i32 * program.register);
+                        }
+                        program.cycle += 1;
+                    }
+                }
+
+                signal_strength
+            })
+            .sum();
+
+        Ok(signal_strength_sum.into())
+    }
+
+    fn part2(instructions: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        use std::cell::RefCell;
+
+        let program = RefCell::new(Program {
+            cycle: 0,
+            register: 1,
+        });
+
+        let crt = instructions
+            .iter()
+            .flat_map(|instruction| match instruction {
+                Instruction::AddX(value) => {
+                    let pixels = (0..2).map(|add_cycle| {
+                        let mut program = program.borrow_mut();
+
+                        let pixel = program.get_pixel();
+
+                        program.cycle += 1;
+
+                        if add_cycle == 1 {
+                            program.register += *value;
+                        }
+
+                        pixel
+                    });
+
+                    Box::new(pixels)
+                }
+                Instruction::Noop => {
+                    let mut program = program.borrow_mut();
+
+                    Box::new(std::iter::once({
+                        let pixel = program.get_pixel();
+
+                        program.cycle += 1;
+
+                        pixel
+                    })) as Box<dyn Iterator<Item = char>>
+                }
+            });
+
+        let mut pixels = String::new();
+
+        for (index, pixel) in crt.enumerate() {
+            if index != 0 && index % 40 == 0 {
+                pixels.push('\n');
+            }
+            pixels.push(pixel);
+        }
+
+        Ok(Answer::Crt(pixels))
+    }
+}
@@ -0,0 +1,101 @@
+mod monkey;
+
+use crate::{AnyResult, Solution};
+use monkey::Monkey;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = Vec<Monkey>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(input
+            .split("\n\n")
+            .map(|notes| {
+                notes
+                    .parse::<Monkey>()
+                    .map_err(|err| err.rebased(input, notes))
+            })
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(monkeys: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let mut monkeys = monkeys.clone();
+
+        for _ in 0..20 {
+            for index in 0..monkeys.len() {
+                let thrower_monkey = unsafe { &mut *monkeys.as_mut_ptr().add(index) };
+
+                thrower_monkey.increase_inspection_count();
+
+                for mut item in thrower_monkey.items.drain(..) {
+                    let mut operation = thrower_monkey.operation.borrow_mut();
+
+                    operation(&mut item);
+
+                    item.apply_relief();
+
+                    let test = &thrower_monkey.test;
+                    let receiver_monkey_index = test(&item);
+
+                    let receiver_monkey =
+                        unsafe { &mut *monkeys.as_mut_ptr().add(receiver_monkey_index) };
+
+                    receiver_monkey.items.push_back(item);
+                }
+            }
+        }
+
+        monkeys.sort_unstable_by_key(|monkey| std::cmp::Reverse(monkey.inspection_count));
+
+        let monkey_business: usize = monkeys[..2]
+            .iter()
+            .map(|monkey| monkey.inspection_count)
+            .product();
+
+        Ok(monkey_business)
+    }
+
+    fn part2(monkeys: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let mut monkeys = monkeys.clone();
+
+        let common_multiple: u64 = monkeys
+            .iter()
+            .map(|monkey| monkey.test_condition_divisor)
+            .product();
+
+        for _ in 0..10_000 {
+            for index in 0..monkeys.len() {
+                let thrower_monkey = unsafe { &mut *monkeys.as_mut_ptr().add(index) };
+
+                thrower_monkey.increase_inspection_count();
+
+                for mut item in thrower_monkey.items.drain(..) {
+                    let mut operation = thrower_monkey.operation.borrow_mut();
+
+                    operation(&mut item);
+
+                    item.manage_worry_level(common_multiple);
+
+                    let test = &thrower_monkey.test;
+                    let receiver_monkey_index = test(&item);
+
+                    let receiver_monkey =
+                        unsafe { &mut *monkeys.as_mut_ptr().add(receiver_monkey_index) };
+
+                    receiver_monkey.items.push_back(item);
+                }
+            }
+        }
+
+        monkeys.sort_unstable_by_key(|monkey| std::cmp::Reverse(monkey.inspection_count));
+
+        let monkey_business: usize = monkeys[..2]
+            .iter()
+            .map(|monkey| monkey.inspection_count)
+            .product();
+
+        Ok(monkey_business)
+    }
+}
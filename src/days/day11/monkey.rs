@@ -0,0 +1,214 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::{cell::RefCell, rc::Rc};
+
+use crate::parse::{parse_number, split_once, ParseError, ParseErrorKind};
+
+type RcCell<T> = Rc<RefCell<T>>;
+
+type Operation = RcCell<dyn FnMut(&mut Item)>;
+type Test = Rc<dyn Fn(&Item) -> usize>;
+
+#[derive(Clone)]
+pub struct Monkey {
+    pub inspection_count: usize,
+    pub items: VecDeque<Item>,
+    pub operation: Operation,
+    pub test: Test,
+    pub test_condition_divisor: u64,
+}
+
+#[derive(Clone)]
+pub struct Item {
+    worry_level: u64,
+}
+
+impl Monkey {
+    pub fn increase_inspection_count(&mut self) {
+        self.inspection_count += self.items.len();
+    }
+
+    fn validate_operation(notes: &str, operation: &str) -> Result<Operation, ParseError> {
+        let (descriptor, operation) = split_once(notes, operation, ": ")?;
+
+        if descriptor != "Operation" {
+            return Err(ParseError::new(
+                ParseErrorKind::Unexpected("`Operation`"),
+                notes,
+                descriptor,
+            ));
+        }
+
+        let operation_components: Vec<_> = operation.split(' ').collect();
+
+        if operation_components.len() != 5 || operation_components[..3] != ["new", "=", "old"] {
+            return Err(ParseError::new(
+                ParseErrorKind::Unexpected("an operation like `new = old * 19`"),
+                notes,
+                operation,
+            ));
+        }
+
+        let operation_function = if operation_components[3] == "+" {
+            <u64 as std::ops::Add>::add
+        } else if operation_components[3] == "*" {
+            if operation_components[4] == "old" {
+                |value, exp| u64::pow(value, exp as u32)
+            } else {
+                <u64 as std::ops::Mul>::mul
+            }
+        } else {
+            return Err(ParseError::new(
+                ParseErrorKind::Unexpected("either `+` or `*`"),
+                notes,
+                operation_components[3],
+            ));
+        };
+
+        let operation_operand = if operation_components[4] == "old" {
+            2
+        } else {
+            parse_number(notes, operation_components[4])?
+        };
+
+        let operation = Rc::new(RefCell::new(move |item: &mut Item| {
+            item.worry_level = operation_function(item.worry_level, operation_operand);
+        }));
+
+        Ok(operation)
+    }
+
+    fn validate_receiver_monkey(
+        notes: &str,
+        receiver_monkey: &str,
+        monkey_type: bool,
+    ) -> Result<usize, ParseError> {
+        let (descriptor, monkey_index) = receiver_monkey.rsplit_once(' ').ok_or_else(|| {
+            ParseError::new(
+                ParseErrorKind::MissingDelimiter(" "),
+                notes,
+                receiver_monkey,
+            )
+        })?;
+
+        let expected_descriptor = if monkey_type {
+            "`If true: throw to monkey`"
+        } else {
+            "`If false: throw to monkey`"
+        };
+
+        if descriptor != format!("If {monkey_type}: throw to monkey") {
+            return Err(ParseError::new(
+                ParseErrorKind::Unexpected(expected_descriptor),
+                notes,
+                descriptor,
+            ));
+        }
+
+        parse_number(notes, monkey_index)
+    }
+
+    fn validate_starting_items(notes: &str, items: &str) -> Result<VecDeque<Item>, ParseError> {
+        let (descriptor, items) = split_once(notes, items, ": ")?;
+
+        if descriptor != "Starting items" {
+            return Err(ParseError::new(
+                ParseErrorKind::Unexpected("`Starting items`"),
+                notes,
+                descriptor,
+            ));
+        }
+
+        items
+            .split(", ")
+            .map(|worry_level| {
+                parse_number(notes, worry_level).map(|worry_level| Item { worry_level })
+            })
+            .collect::<Result<_, _>>()
+    }
+
+    fn validate_test_condition(notes: &str, test_condition: &str) -> Result<u64, ParseError> {
+        let (descriptor, divisor) = test_condition.rsplit_once(' ').ok_or_else(|| {
+            ParseError::new(ParseErrorKind::MissingDelimiter(" "), notes, test_condition)
+        })?;
+
+        if descriptor != "Test: divisible by" {
+            return Err(ParseError::new(
+                ParseErrorKind::Unexpected("`Test: divisible by`"),
+                notes,
+                descriptor,
+            ));
+        }
+
+        parse_number(notes, divisor)
+    }
+
+    fn validate_title(notes: &str, title: &str) -> Result<(), ParseError> {
+        let is_title_correct = title
+            .strip_prefix("Monkey ")
+            .and_then(|index| index.strip_suffix(':'))
+            .is_some_and(|index| index.parse::<usize>().is_ok());
+
+        if is_title_correct {
+            Ok(())
+        } else {
+            Err(ParseError::new(
+                ParseErrorKind::Unexpected("a title like `Monkey 0:`"),
+                notes,
+                title,
+            ))
+        }
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let notes: Vec<&str> = s.lines().map(str::trim).collect();
+
+        if notes.len() < 6 {
+            return Err(ParseError::missing(
+                "the rest of the monkey's notes, which should be 6 lines long",
+                s,
+            ));
+        }
+
+        Self::validate_title(s, notes[0])?;
+
+        let items = Self::validate_starting_items(s, notes[1])?;
+        let operation = Self::validate_operation(s, notes[2])?;
+        let test_condition_divisor = Self::validate_test_condition(s, notes[3])?;
+
+        let true_monkey_index = Self::validate_receiver_monkey(s, notes[4], true)?;
+        let false_monkey_index = Self::validate_receiver_monkey(s, notes[5], false)?;
+
+        let test = Rc::new(move |item: &Item| {
+            if item.worry_level.is_multiple_of(test_condition_divisor) {
+                true_monkey_index
+            } else {
+                false_monkey_index
+            }
+        });
+
+        Ok(Monkey {
+            inspection_count: 0,
+            items,
+            operation,
+            test,
+            test_condition_divisor,
+        })
+    }
+}
+
+impl Item {
+    pub fn apply_relief(&mut self) {
+        self.worry_level /= 3;
+    }
+
+    /// Keeps the worry level bounded without changing the outcome of any monkey's test,
+    /// given a multiple of every monkey's test condition divisor.
+    pub fn manage_worry_level(&mut self, common_multiple: u64) {
+        self.worry_level %= common_multiple;
+    }
+}
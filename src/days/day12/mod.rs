@@ -0,0 +1,250 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    grid::Grid,
+    parse::ParseError,
+    search::{bfs, SearchResult},
+    AnyResult, CustomError, Solution,
+};
+
+pub struct Day12;
+
+type Position = (usize, usize);
+
+pub struct Heightmap {
+    end: Position,
+    elevations: Grid<u32>,
+    start: Position,
+}
+
+/// Which way to search for a route up the hill.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Traversal {
+    /// From the start position `S` up to the end `E`, as in part 1.
+    Forward,
+    /// Down from the end `E` to whichever of the lowest squares is nearest, as in part 2.
+    Reverse,
+}
+
+/// The colour of the lowest squares in pictures of the heightmap.
+const VALLEY_COLOUR: [u8; 3] = [34, 92, 44];
+/// The colour of squares part of the way up, where the grass gives way to rock.
+const SLOPE_COLOUR: [u8; 3] = [128, 104, 72];
+/// The colour of the highest squares.
+const PEAK_COLOUR: [u8; 3] = [236, 236, 240];
+/// The colour of the square the route starts from.
+const START_COLOUR: [u8; 3] = [220, 40, 40];
+/// The colour of the squares along the route, in PPM images.
+const ROUTE_COLOUR: [u8; 3] = [250, 210, 60];
+
+impl Heightmap {
+    /// Searches for the fewest steps from the start to the end, or, in reverse, from the end to
+    /// any of the lowest squares.
+    fn search(&self, traversal: Traversal) -> SearchResult<Position, usize> {
+        let lowest_elevation = 'a' as u32;
+
+        let neighbors = |&position: &Position| self.neighbors(position, traversal);
+
+        match traversal {
+            Traversal::Forward => bfs(&neighbors, [self.start], |&position| position == self.end),
+            Traversal::Reverse => bfs(&neighbors, [self.end], |&position| {
+                self.elevations[position] == lowest_elevation
+            }),
+        }
+    }
+
+    /// Returns the shortest route up the hill found searching the given way, in the order it's
+    /// walked up, or `None` if there isn't one.
+    pub fn route(&self, traversal: Traversal) -> Option<Route<'_>> {
+        let mut squares = self.search(traversal).path()?;
+
+        if traversal == Traversal::Reverse {
+            squares.reverse();
+        }
+
+        Some(Route {
+            heightmap: self,
+            squares,
+        })
+    }
+
+    /// Returns the colour a square is shaded in pictures, going from green valleys to snowy
+    /// peaks.
+    fn shade(&self, position: Position) -> [u8; 3] {
+        let height = self.elevations[position].saturating_sub('a' as u32).min(25) as f32 / 25.0;
+
+        let (from, to, t) = if height < 0.6 {
+            (VALLEY_COLOUR, SLOPE_COLOUR, height / 0.6)
+        } else {
+            (SLOPE_COLOUR, PEAK_COLOUR, (height - 0.6) / 0.4)
+        };
+
+        [0, 1, 2].map(|channel| {
+            (from[channel] as f32 + (to[channel] as f32 - from[channel] as f32) * t).round() as u8
+        })
+    }
+
+    fn neighbors(
+        &self,
+        position: Position,
+        traversal: Traversal,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let elevation = self.elevations[position];
+
+        self.elevations
+            .neighbors4(position)
+            .filter(move |&neighbor| match traversal {
+                Traversal::Forward => self.elevations[neighbor] <= elevation + 1,
+                Traversal::Reverse => self.elevations[neighbor] >= elevation - 1,
+            })
+    }
+}
+
+/// A shortest route up the hill, from its start to the end `E`.
+pub struct Route<'a> {
+    heightmap: &'a Heightmap,
+    /// Every square along the route, in the order they're walked.
+    squares: Vec<Position>,
+}
+
+impl Route<'_> {
+    /// Returns the square the route starts from, which is `S` going forwards, or the nearest of
+    /// the lowest squares in reverse.
+    pub fn start(&self) -> Position {
+        self.squares[0]
+    }
+
+    pub fn steps(&self) -> usize {
+        self.squares.len() - 1
+    }
+
+    /// Draws the route as the puzzle does, with an arrow on each square showing which way the
+    /// route leaves it, `E` at the end and `.` everywhere off the route.
+    pub fn arrows(&self) -> Grid<char> {
+        let elevations = &self.heightmap.elevations;
+        let mut arrows = Grid::new(elevations.width(), elevations.height(), '.');
+
+        for pair in self.squares.windows(2) {
+            let ((x, y), (next_x, next_y)) = (pair[0], pair[1]);
+
+            arrows[(x, y)] = if next_x > x {
+                '>'
+            } else if next_x < x {
+                '<'
+            } else if next_y > y {
+                'v'
+            } else {
+                '^'
+            };
+        }
+
+        arrows[self.heightmap.end] = 'E';
+
+        arrows
+    }
+
+    /// Draws the route like [`Self::arrows`], over the heightmap shaded by elevation with ANSI
+    /// escape codes, and with the start highlighted.
+    pub fn to_ansi(&self) -> String {
+        let arrows = self.arrows();
+        let mut ansi = String::new();
+
+        for (position, &arrow) in arrows.iter() {
+            let [red, green, blue] = if position == self.start() {
+                START_COLOUR
+            } else {
+                self.heightmap.shade(position)
+            };
+
+            ansi += &format!("\x1b[48;2;{red};{green};{blue}m{arrow}");
+
+            if position.0 == arrows.width() - 1 {
+                ansi += "\x1b[0m\n";
+            }
+        }
+
+        ansi
+    }
+
+    /// Draws the heightmap shaded by elevation as a PPM image, with the route over it and the
+    /// start highlighted.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let elevations = &self.heightmap.elevations;
+
+        let shaded = elevations
+            .positions()
+            .map(|position| self.heightmap.shade(position))
+            .collect();
+
+        // There is one colour for every square of the heightmap.
+        let mut picture = Grid::from_cells(elevations.width(), shaded).unwrap();
+
+        for &square in &self.squares {
+            picture[square] = ROUTE_COLOUR;
+        }
+
+        picture[self.start()] = START_COLOUR;
+
+        picture.to_ppm(|&colour| colour)
+    }
+}
+
+/// Draws the route in the puzzle's notation, as given by [`Route::arrows`].
+impl fmt::Display for Route<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.arrows())
+    }
+}
+
+impl FromStr for Heightmap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse(
+            s,
+            |char| matches!(char, 'a'..='z' | 'S' | 'E').then_some(char),
+            "an elevation from `a` to `z`, `S` or `E`",
+        )?;
+
+        let elevations = map.map(|&char| match char {
+            'S' => 'a' as u32,
+            'E' => 'z' as u32,
+            other => other as u32,
+        });
+
+        Ok(Heightmap {
+            end: map
+                .find(|&char| char == 'E')
+                .ok_or_else(|| ParseError::missing("the end position `E`", s))?,
+            elevations,
+            start: map
+                .find(|&char| char == 'S')
+                .ok_or_else(|| ParseError::missing("the start position `S`", s))?,
+        })
+    }
+}
+
+impl Solution for Day12 {
+    type Parsed<'a> = Heightmap;
+    type Answer = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(heightmap: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        steps(heightmap, Traversal::Forward)
+    }
+
+    fn part2(heightmap: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        steps(heightmap, Traversal::Reverse)
+    }
+}
+
+fn steps(heightmap: &Heightmap, traversal: Traversal) -> AnyResult<usize> {
+    let steps = heightmap.search(traversal).goal_cost().ok_or(CustomError {
+        msg: "No route to the destination exists.".into(),
+    })?;
+
+    Ok(steps)
+}
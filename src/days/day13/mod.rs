@@ -0,0 +1,191 @@
+use std::{
+    iter::Peekable,
+    str::{CharIndices, FromStr},
+};
+
+use crate::{
+    parse::{parse_number, split_once, ParseError, ParseErrorKind},
+    AnyResult, Solution,
+};
+
+pub struct Day13;
+
+#[derive(Clone, Debug)]
+pub enum PacketData {
+    Int(u32),
+    List(Vec<PacketData>),
+}
+
+impl PacketData {
+    fn parse_recursive(s: &str, chars: &mut Peekable<CharIndices>) -> Result<Self, ParseError> {
+        let unexpected = |expected, index: usize, char: char| {
+            ParseError::new(
+                ParseErrorKind::Unexpected(expected),
+                s,
+                &s[index..index + char.len_utf8()],
+            )
+        };
+
+        match chars.next() {
+            Some((_, '[')) => {
+                let mut packet_data = Vec::new();
+
+                if let Some((_, ']')) = chars.peek() {
+                    chars.next();
+                    return Ok(PacketData::List(packet_data));
+                }
+
+                loop {
+                    packet_data.push(Self::parse_recursive(s, chars)?);
+
+                    match chars.next() {
+                        Some((_, ',')) => continue,
+                        Some((_, ']')) => break,
+                        Some((index, char)) => return Err(unexpected("`,` or `]`", index, char)),
+                        None => return Err(ParseError::missing("a closing `]`", s)),
+                    }
+                }
+
+                Ok(PacketData::List(packet_data))
+            }
+
+            Some((start, digit)) if digit.is_ascii_digit() => {
+                let mut end = start + 1;
+
+                while let Some(&(index, digit)) = chars.peek() {
+                    if !digit.is_ascii_digit() {
+                        break;
+                    }
+
+                    end = index + 1;
+                    chars.next();
+                }
+
+                parse_number(s, &s[start..end]).map(Self::Int)
+            }
+
+            Some((index, char)) => Err(unexpected("a list or an integer", index, char)),
+
+            None => Err(ParseError::missing("a list or an integer", s)),
+        }
+    }
+}
+
+impl FromStr for PacketData {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.char_indices().peekable();
+
+        let packet_data = Self::parse_recursive(s, &mut chars)?;
+
+        match chars.next() {
+            None => Ok(packet_data),
+            Some((index, _)) => Err(ParseError::new(
+                ParseErrorKind::Unexpected("the end of the packet"),
+                s,
+                &s[index..],
+            )),
+        }
+    }
+}
+
+impl PartialEq for PacketData {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Int(int1), Self::Int(int2)) => int1 == int2,
+            (&Self::Int(int), Self::List(list)) | (Self::List(list), &Self::Int(int)) => {
+                list == &vec![Self::Int(int)]
+            }
+            (Self::List(list1), Self::List(list2)) => list1 == list2,
+        }
+    }
+}
+
+impl Eq for PacketData {}
+
+impl PartialOrd for PacketData {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PacketData {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (PacketData::Int(int1), PacketData::Int(int2)) => int1.cmp(int2),
+            (&PacketData::Int(int), PacketData::List(list)) => vec![Self::Int(int)].cmp(list),
+            (PacketData::List(list), &PacketData::Int(int)) => list.cmp(&vec![Self::Int(int)]),
+            (PacketData::List(list1), PacketData::List(list2)) => list1.cmp(list2),
+        }
+    }
+}
+
+impl Solution for Day13 {
+    type Parsed<'a> = Vec<(PacketData, PacketData)>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        input
+            .split("\n\n")
+            .map(|packet_pair| {
+                let (packet1, packet2) = split_once(input, packet_pair, "\n")?;
+                let packet2 = packet2.trim_end();
+
+                let parse_packet = |packet: &str| {
+                    packet
+                        .parse()
+                        .map_err(|err: ParseError| err.rebased(input, packet))
+                };
+
+                Ok((parse_packet(packet1)?, parse_packet(packet2)?))
+            })
+            .collect()
+    }
+
+    fn part1(packet_pairs: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let mut correct_index_sum = 0;
+
+        for (index, (packet1, packet2)) in packet_pairs.iter().enumerate() {
+            if packet1 < packet2 {
+                correct_index_sum += index + 1;
+            }
+        }
+
+        Ok(correct_index_sum)
+    }
+
+    fn part2(packet_pairs: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let mut packets: Vec<PacketData> = packet_pairs
+            .iter()
+            .flat_map(|(packet1, packet2)| [packet1.clone(), packet2.clone()])
+            .collect();
+
+        packets.push(PacketData::List(vec![PacketData::List(vec![
+            PacketData::Int(2),
+        ])]));
+        packets.push(PacketData::List(vec![PacketData::List(vec![
+            PacketData::Int(6),
+        ])]));
+
+        packets.sort();
+
+        let start_index = packets
+            .binary_search(&PacketData::List(vec![PacketData::List(vec![
+                PacketData::Int(2),
+            ])]))
+            .unwrap()
+            + 1;
+
+        let end_index = packets
+            .binary_search(&PacketData::List(vec![PacketData::List(vec![
+                PacketData::Int(6),
+            ])]))
+            .unwrap()
+            + 1;
+
+        let index_sum = start_index * end_index;
+
+        Ok(index_sum)
+    }
+}
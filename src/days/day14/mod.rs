@@ -0,0 +1,222 @@
+use crate::{
+    geometry::{Aabb2, Direction8, Point2},
+    grid::Grid,
+    parse::{ParseError, ParseErrorKind},
+    AnyResult, Solution,
+};
+
+pub struct Day14;
+
+type Position = Point2<i32>;
+
+/// Where the sand pours in from.
+const SOURCE: Position = Position::new(500, 0);
+
+/// Where a unit of sand tries to move, in order of preference.
+const FALL_DIRECTIONS: [Direction8; 3] = [
+    Direction8::Down,
+    Direction8::DownLeft,
+    Direction8::DownRight,
+];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+/// The slice of the cave which sand can fall through, down to where the floor would be.
+#[derive(Clone, Debug)]
+pub struct Cave {
+    /// The tiles from `y = 0` down to the floor, which is the bottom row. The cave is wide
+    /// enough that sand can never leave its sides.
+    tiles: Grid<Tile>,
+    /// The `x` coordinate of the left-most column of `tiles`.
+    left: i32,
+}
+
+impl Cave {
+    /// Creates the cave from the paths of rock through it, with nothing below the lowest rock.
+    fn new(rock_paths: &[Vec<Position>]) -> Self {
+        let lowest_rock = rock_paths.iter().flatten().map(|vertex| vertex.y).max();
+        let floor = lowest_rock.unwrap_or_default() + 2;
+
+        // Sand can't move further sideways than it has fallen, so never leaves the triangle
+        // below the source which reaches as far either side as the floor is deep.
+        let bounds = Aabb2::from_points(rock_paths.iter().flatten().copied())
+            .unwrap_or(Aabb2::new(SOURCE, SOURCE))
+            .including(Position::new(SOURCE.x - floor, floor))
+            .including(Position::new(SOURCE.x + floor, 0))
+            .expanded(1);
+
+        let mut tiles = Grid::new(bounds.width() as usize, floor as usize + 1, Tile::Air);
+
+        for path in rock_paths {
+            for pair in path.windows(2) {
+                for position in Aabb2::new(pair[0], pair[1]).positions() {
+                    tiles[((position.x - bounds.min.x) as usize, position.y as usize)] = Tile::Rock;
+                }
+            }
+        }
+
+        Self {
+            tiles,
+            left: bounds.min.x,
+        }
+    }
+
+    /// Returns the same cave with a floor two below the lowest rock, which stretches out
+    /// further than the sand can ever spread.
+    pub fn with_floor(&self) -> Self {
+        let mut cave = self.clone();
+        let floor = cave.tiles.height() - 1;
+
+        for x in 0..cave.tiles.width() {
+            cave.tiles[(x, floor)] = Tile::Rock;
+        }
+
+        cave
+    }
+
+    fn tile_index(&self, position: Position) -> (usize, usize) {
+        ((position.x - self.left) as usize, position.y as usize)
+    }
+
+    /// Pours sand in from the source until either a unit falls past everything into the abyss,
+    /// or the sand piles up high enough to block the source, returning how many units came to
+    /// rest.
+    pub fn pour_sand(&mut self) -> usize {
+        let bottom = self.tiles.height() as i32 - 1;
+
+        // Each unit of sand falls along the same path as the one before it until the point where
+        // that one came to rest, so the path is kept and only the end of it is retraced.
+        let mut path = vec![SOURCE];
+        let mut num_resting = 0;
+
+        while let Some(&position) = path.last() {
+            if position.y == bottom {
+                break;
+            }
+
+            let next = FALL_DIRECTIONS
+                .into_iter()
+                .map(|direction| position + direction.step())
+                .find(|&next| self.tiles[self.tile_index(next)] == Tile::Air);
+
+            match next {
+                Some(next) => path.push(next),
+                None => {
+                    let index = self.tile_index(position);
+
+                    self.tiles[index] = Tile::Sand;
+                    num_resting += 1;
+                    path.pop();
+                }
+            }
+        }
+
+        num_resting
+    }
+
+    /// Draws the part of the cave around the rock, sand and source as the puzzle does, with `#`
+    /// for rock, `o` for sand, `+` for the source and `.` for air.
+    pub fn picture(&self) -> Grid<char> {
+        let floor = self.tiles.height() - 1;
+        let has_floor = self.tiles[(0, floor)] == Tile::Rock;
+
+        let filled = self
+            .tiles
+            .iter()
+            .filter(|&((_, y), &tile)| y < floor && tile != Tile::Air)
+            .map(|((x, y), _)| Position::new(x as i32 + self.left, y as i32));
+
+        let mut bounds = Aabb2::from_points(filled).map_or(Aabb2::new(SOURCE, SOURCE), |bounds| {
+            bounds.including(SOURCE)
+        });
+
+        if has_floor {
+            bounds = bounds.including(Position::new(SOURCE.x, floor as i32));
+        }
+
+        let cells = bounds
+            .positions()
+            .map(|position| match self.tiles[self.tile_index(position)] {
+                Tile::Air if position == SOURCE => '+',
+                Tile::Air => '.',
+                Tile::Rock => '#',
+                Tile::Sand => 'o',
+            })
+            .collect();
+
+        // There is one cell for each position in the bounds.
+        Grid::from_cells(bounds.width() as usize, cells).unwrap()
+    }
+}
+
+/// Parses the scan of the cave, with one path of rock on each line.
+pub fn parse_cave(input: &str) -> Result<Cave, ParseError> {
+    let mut rock_paths = Vec::new();
+
+    for line in input.lines() {
+        let rock_vertices: Vec<(&str, Position)> = line
+            .split(" -> ")
+            .map(|vertex| {
+                vertex
+                    .parse()
+                    .map(|position| (vertex, position))
+                    .map_err(|err: ParseError| err.rebased(input, vertex))
+            })
+            .collect::<Result<_, _>>()?;
+
+        for &(vertex_text, vertex) in &rock_vertices {
+            if vertex.y < 0 {
+                return Err(ParseError::new(
+                    ParseErrorKind::Unexpected("a point no higher than the sand's source"),
+                    input,
+                    vertex_text,
+                ));
+            }
+        }
+
+        let mut vertex_pairs = rock_vertices.windows(2);
+
+        while let Some([(_, vertex), (vertex2_text, vertex2)]) = vertex_pairs.next() {
+            if vertex == vertex2 || (vertex.x != vertex2.x && vertex.y != vertex2.y) {
+                return Err(ParseError::new(
+                    ParseErrorKind::Unexpected(
+                        "a different vertex to the one before it, in line with it",
+                    ),
+                    input,
+                    vertex2_text,
+                ));
+            }
+        }
+
+        rock_paths.push(
+            rock_vertices
+                .into_iter()
+                .map(|(_, vertex)| vertex)
+                .collect(),
+        );
+    }
+
+    Ok(Cave::new(&rock_paths))
+}
+
+impl Solution for Day14 {
+    type Parsed<'a> = Cave;
+    type Answer = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(parse_cave(input)?)
+    }
+
+    fn part1(cave: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        Ok(cave.clone().pour_sand())
+    }
+
+    fn part2(cave: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        Ok(cave.with_floor().pour_sand())
+    }
+}
@@ -0,0 +1,220 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use crate::{
+    geometry::{Aabb2, Point2},
+    parse::{parse_number, ParseError, ParseErrorKind},
+    AnyResult, CustomError, Solution,
+};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+pub struct Day15;
+
+/// The row part 1 asks about. The puzzle's example asks about row 10 instead.
+pub const PUZZLE_ROW: i32 = 2_000_000;
+
+/// The largest either coordinate of the distress beacon can be. The puzzle's example uses 20
+/// instead.
+pub const PUZZLE_BOUND: i32 = 4_000_000;
+
+static SENSOR_BEACON_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new("Sensor at x=(?P<sensor_x>-?\\d+), y=(?P<sensor_y>-?\\d+): closest beacon is at x=(?P<beacon_x>-?\\d+), y=(?P<beacon_y>-?\\d+)").unwrap()
+});
+
+pub struct BeaconExclusionZone {
+    beacons: Vec<Position>,
+    sensors: Vec<Sensor>,
+}
+
+impl BeaconExclusionZone {
+    /// Returns the stretches of `row` which are within range of a sensor, from left to right,
+    /// merged so that no two of them overlap or touch.
+    pub fn coverage(&self, row: i32) -> Vec<RangeInclusive<i32>> {
+        let mut ranges: Vec<RangeInclusive<i32>> = self
+            .sensors
+            .iter()
+            .filter_map(|sensor| sensor.coverage(row))
+            .collect();
+
+        ranges.sort_unstable_by_key(|range| *range.start());
+
+        let mut merged: Vec<RangeInclusive<i32>> = Vec::with_capacity(ranges.len());
+
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if *range.start() <= last.end() + 1 => {
+                    *last = *last.start()..=*range.end().max(last.end());
+                }
+                _ => merged.push(range),
+            }
+        }
+
+        merged
+    }
+
+    /// Returns how many positions in `row` can't contain a beacon, as they're closer to a sensor
+    /// than the beacon it found, without being that beacon.
+    pub fn num_without_beacon(&self, row: i32) -> u64 {
+        let covered: u64 = self
+            .coverage(row)
+            .into_iter()
+            .map(|range| (range.end() - range.start()) as u64 + 1)
+            .sum();
+
+        // Each beacon is within range of the sensor which found it, so is always covered.
+        let mut beacons_in_row: Vec<i32> = self
+            .beacons
+            .iter()
+            .filter(|beacon| beacon.y == row)
+            .map(|beacon| beacon.x)
+            .collect();
+
+        beacons_in_row.sort_unstable();
+        beacons_in_row.dedup();
+
+        covered - beacons_in_row.len() as u64
+    }
+
+    /// Returns the position of the distress beacon, which is the only one with both coordinates
+    /// between 0 and `bound` that's out of range of every sensor.
+    ///
+    /// Being the only such position, it must lie just outside the range of the sensors around
+    /// it, so only the points where the lines bordering their ranges cross each other or the
+    /// edges of the search area are checked.
+    pub fn distress_beacon(&self, bound: i32) -> Option<Position> {
+        let search_area = Aabb2::new(Position::new(0, 0), Position::new(bound, bound));
+
+        // The lines bordering each sensor's range, as the constant `x + y` or `x - y` along them,
+        // starting with the diagonals through the corners of the search area.
+        let (mut sums, mut differences) = (vec![0, 2 * bound], vec![-bound, bound]);
+
+        for sensor in &self.sensors {
+            let Position { x, y } = sensor.position;
+            let distance = sensor.beacon_distance + 1;
+
+            sums.extend([x + y - distance, x + y + distance]);
+            differences.extend([x - y - distance, x - y + distance]);
+        }
+
+        let crossings = sums.iter().flat_map(|&sum| {
+            differences
+                .iter()
+                .filter(move |&&difference| (sum - difference) % 2 == 0)
+                .map(move |&difference| {
+                    Position::new((sum + difference) / 2, (sum - difference) / 2)
+                })
+        });
+
+        // Every point where a line of either kind could meet an edge, some of which are only
+        // where the other kind would.
+        let along_edges = sums.iter().chain(&differences).flat_map(|&constant| {
+            [0, bound].into_iter().flat_map(move |edge| {
+                [
+                    Position::new(edge, constant - edge),
+                    Position::new(constant - edge, edge),
+                    Position::new(edge, edge - constant),
+                    Position::new(edge + constant, edge),
+                ]
+            })
+        });
+
+        crossings
+            .chain(along_edges)
+            .filter(|&position| search_area.contains(position))
+            .find(|&position| {
+                self.sensors.iter().all(|sensor| {
+                    sensor.position.manhattan_distance(position) > sensor.beacon_distance
+                })
+            })
+    }
+}
+
+/// Returns the tuning frequency of a distress beacon at the given position.
+pub fn tuning_frequency(position: Position) -> u64 {
+    position.x as u64 * 4_000_000 + position.y as u64
+}
+
+#[derive(Debug)]
+pub struct Sensor {
+    beacon_distance: i32,
+    position: Position,
+}
+
+impl Sensor {
+    /// Returns the stretch of `row` which is within range of this sensor, if any of it is.
+    fn coverage(&self, row: i32) -> Option<RangeInclusive<i32>> {
+        let distance_along_row = self.beacon_distance - (self.position.y - row).abs();
+
+        (distance_along_row >= 0).then(|| {
+            (self.position.x - distance_along_row)..=(self.position.x + distance_along_row)
+        })
+    }
+}
+
+type Position = Point2<i32>;
+
+impl FromStr for BeaconExclusionZone {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut beacons = Vec::new();
+
+        let mut sensors = Vec::new();
+
+        for line in s.lines() {
+            let captures = SENSOR_BEACON_REGEX.captures(line).ok_or_else(|| {
+                ParseError::new(
+                    ParseErrorKind::Unexpected(
+                        "a line like `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`",
+                    ),
+                    s,
+                    line,
+                )
+            })?;
+
+            // Every group is always present when the regex matches.
+            let capture_to_i32 = |name| parse_number::<i32>(s, &captures[name]);
+
+            let sensor_x = capture_to_i32("sensor_x")?;
+            let sensor_y = capture_to_i32("sensor_y")?;
+            let beacon_x = capture_to_i32("beacon_x")?;
+            let beacon_y = capture_to_i32("beacon_y")?;
+
+            let sensor_position = Position::new(sensor_x, sensor_y);
+            let beacon_position = Position::new(beacon_x, beacon_y);
+
+            sensors.push(Sensor {
+                beacon_distance: sensor_position.manhattan_distance(beacon_position),
+                position: sensor_position,
+            });
+
+            beacons.push(beacon_position);
+        }
+
+        Ok(Self { beacons, sensors })
+    }
+}
+
+impl Solution for Day15 {
+    type Parsed<'a> = BeaconExclusionZone;
+    type Answer = u64;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(beacon_exclusion_zone: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        Ok(beacon_exclusion_zone.num_without_beacon(PUZZLE_ROW))
+    }
+
+    fn part2(beacon_exclusion_zone: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let distress_beacon =
+            beacon_exclusion_zone
+                .distress_beacon(PUZZLE_BOUND)
+                .ok_or(CustomError {
+                    msg: "No position was out of range of all sensors.".into(),
+                })?;
+
+        Ok(tuning_frequency(distress_beacon))
+    }
+}
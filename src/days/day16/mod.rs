@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+
+use crate::{
+    parse::{parse_number, ParseError, ParseErrorKind},
+    search::bfs,
+    AnyResult, CustomError, Solution,
+};
+use once_cell::sync::Lazy;
+use petgraph::prelude::{Graph, NodeIndex};
+use regex::Regex;
+
+pub struct Day16;
+
+pub type RoomGraph<'a> = Graph<Valve<'a>, u32, petgraph::Undirected>;
+
+static ROOM_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new("Valve (?P<id>[A-Z]{2}) has flow rate=(?P<flow_rate>\\d+); tunnels? leads? to valves? (?P<edges>(?:[A-Z]{2}(?:, )?)+)")
+        .unwrap()
+});
+
+#[derive(Clone, Debug)]
+pub struct Valve<'a> {
+    flow_rate: i32,
+    id: RoomId<'a>,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RoomId<'a>(&'a str);
+
+impl<'a> TryFrom<&'a str> for RoomId<'a> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        if s.len() == 2 {
+            Ok(Self(s))
+        } else {
+            Err(ParseError::new(
+                ParseErrorKind::Unexpected("a room ID of 2 letters"),
+                s,
+                s,
+            ))
+        }
+    }
+}
+
+fn create_graph<'a>(
+    input: &'a str,
+) -> Result<(RoomGraph<'a>, HashMap<RoomId<'a>, NodeIndex>), ParseError> {
+    let mut graph: RoomGraph = Graph::new_undirected();
+
+    let mut id_to_index: HashMap<RoomId, NodeIndex> = HashMap::new();
+
+    let mut edges: HashMap<RoomId, Vec<RoomId>> = HashMap::new();
+
+    for line in input.lines() {
+        let captures = ROOM_REGEX.captures(line).ok_or_else(|| {
+            ParseError::new(
+                ParseErrorKind::Unexpected(
+                    "a line like `Valve AA has flow rate=0; tunnels lead to valves DD, II`",
+                ),
+                input,
+                line,
+            )
+        })?;
+
+        // Every group is always present when the regex matches.
+        let room_id = |id: &'a str| RoomId::try_from(id).map_err(|err| err.rebased(input, id));
+
+        let id = room_id(captures.name("id").unwrap().as_str())?;
+
+        let flow_rate: i32 = parse_number(input, &captures["flow_rate"])?;
+
+        let valve = Valve {
+            flow_rate,
+            id: id.clone(),
+        };
+
+        let index = graph.add_node(valve);
+        id_to_index.insert(id.clone(), index);
+
+        let node_edges = captures
+            .name("edges")
+            .unwrap()
+            .as_str()
+            .split(", ")
+            .map(room_id)
+            .collect::<Result<_, _>>()?;
+
+        edges.insert(id, node_edges);
+    }
+
+    for (id, adjacent_node_ids) in edges {
+        let node_index = id_to_index[&id];
+
+        for adjacent_node_id in adjacent_node_ids {
+            let adjacent_node_index = *id_to_index.get(&adjacent_node_id).ok_or_else(|| {
+                ParseError::new(
+                    ParseErrorKind::Unexpected("a valve which is described by its own line"),
+                    input,
+                    adjacent_node_id.0,
+                )
+            })?;
+
+            graph.add_edge(node_index, adjacent_node_index, 1);
+        }
+    }
+
+    if !id_to_index.contains_key(&RoomId("AA")) {
+        return Err(ParseError::missing("the starting valve `AA`", input));
+    }
+
+    Ok((graph, id_to_index))
+}
+
+/// Returns the number of steps between every pair of valves with a positive flow rate, and
+/// from the starting valve to each of them.
+fn get_route_lengths(graph: &RoomGraph) -> HashMap<(NodeIndex, NodeIndex), i32> {
+    let neighbors = |&index: &NodeIndex| graph.neighbors(index);
+
+    let mut route_lengths = HashMap::new();
+
+    for from in graph.node_indices() {
+        if graph[from].flow_rate == 0 && graph[from].id != RoomId("AA") {
+            continue;
+        }
+
+        for (&to, &steps) in bfs(&neighbors, [from], |_| false).costs() {
+            if graph[to].flow_rate > 0 {
+                route_lengths.insert((from, to), steps as i32);
+            }
+        }
+    }
+
+    route_lengths
+}
+
+/// The most valves with a positive flow rate which can be solved for, as every set of them is
+/// given a slot in a table.
+const MAX_WORKING_VALVES: usize = 20;
+
+/// The valves worth opening, numbered from 0 so that a set of them can be stored as a bitmask.
+struct WorkingValves {
+    flow_rates: Vec<i32>,
+    /// The number of steps from each valve to each other valve.
+    route_lengths: Vec<Vec<i32>>,
+    /// The number of steps from the starting room to each valve.
+    start_route_lengths: Vec<i32>,
+}
+
+impl WorkingValves {
+    fn new(graph: &RoomGraph, id_to_index: &HashMap<RoomId, NodeIndex>) -> AnyResult<Self> {
+        let route_lengths = get_route_lengths(graph);
+
+        let indices: Vec<NodeIndex> = graph
+            .node_indices()
+            .filter(|&index| graph[index].flow_rate > 0)
+            .collect();
+
+        if indices.len() > MAX_WORKING_VALVES {
+            return Err(Box::new(CustomError {
+                msg: format!(
+                    "Only up to {MAX_WORKING_VALVES} valves with a positive flow rate are \
+                     supported, found {}.",
+                    indices.len()
+                )
+                .into(),
+            }));
+        }
+
+        // Parsing checks that the starting valve is there.
+        let start = id_to_index[&RoomId("AA")];
+
+        let route_length = |from: NodeIndex, to: NodeIndex| {
+            route_lengths
+                .get(&(from, to))
+                .copied()
+                .ok_or_else(|| CustomError {
+                    msg: format!(
+                        "Valve {} can't be reached from valve {}.",
+                        graph[to].id.0, graph[from].id.0
+                    )
+                    .into(),
+                })
+        };
+
+        Ok(Self {
+            flow_rates: indices
+                .iter()
+                .map(|&index| graph[index].flow_rate)
+                .collect(),
+            route_lengths: indices
+                .iter()
+                .map(|&from| indices.iter().map(|&to| route_length(from, to)).collect())
+                .collect::<Result<_, _>>()?,
+            start_route_lengths: indices
+                .iter()
+                .map(|&to| route_length(start, to))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn all(&self) -> usize {
+        (1 << self.flow_rates.len()) - 1
+    }
+
+    /// Returns, for every set of valves as a bitmask, the most pressure which can be released in
+    /// the given number of minutes by opening exactly those valves, or 0 if they can't all be
+    /// opened in time.
+    fn most_pressure_by_opened(&self, minutes: i32) -> Vec<i32> {
+        let mut most_pressure = vec![0; self.all() + 1];
+
+        for (valve, &steps) in self.start_route_lengths.iter().enumerate() {
+            self.open(valve, minutes - steps - 1, 0, 0, &mut most_pressure);
+        }
+
+        most_pressure
+    }
+
+    /// Opens `valve` with `minutes_remaining` left after doing so, then tries opening every
+    /// other closed valve next.
+    fn open(
+        &self,
+        valve: usize,
+        minutes_remaining: i32,
+        opened: usize,
+        pressure_released: i32,
+        most_pressure: &mut [i32],
+    ) {
+        if minutes_remaining <= 0 {
+            return;
+        }
+
+        let opened = opened | 1 << valve;
+        let pressure_released = pressure_released + self.flow_rates[valve] * minutes_remaining;
+
+        most_pressure[opened] = most_pressure[opened].max(pressure_released);
+
+        for (next, &steps) in self.route_lengths[valve].iter().enumerate() {
+            if opened & 1 << next == 0 {
+                self.open(
+                    next,
+                    minutes_remaining - steps - 1,
+                    opened,
+                    pressure_released,
+                    most_pressure,
+                );
+            }
+        }
+    }
+}
+
+impl Solution for Day16 {
+    type Parsed<'a> = (RoomGraph<'a>, HashMap<RoomId<'a>, NodeIndex>);
+    type Answer = i32;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(create_graph(input)?)
+    }
+
+    fn part1((graph, id_to_index): &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let valves = WorkingValves::new(graph, id_to_index)?;
+
+        let most_pressure = valves.most_pressure_by_opened(30);
+
+        Ok(most_pressure.into_iter().max().unwrap_or_default())
+    }
+
+    fn part2((graph, id_to_index): &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let valves = WorkingValves::new(graph, id_to_index)?;
+
+        let most_pressure = valves.most_pressure_by_opened(26);
+
+        // The most pressure which can be released by opening any subset of each set of valves.
+        let mut most_pressure_within = most_pressure.clone();
+
+        for valve in 0..valves.flow_rates.len() {
+            for opened in 0..most_pressure_within.len() {
+                if opened & 1 << valve != 0 {
+                    most_pressure_within[opened] =
+                        most_pressure_within[opened].max(most_pressure_within[opened ^ 1 << valve]);
+                }
+            }
+        }
+
+        // You and the elephant never need to open the same valve, so whichever valves you open,
+        // the elephant does best opening some of the rest.
+        let total_pressure_released = most_pressure
+            .iter()
+            .enumerate()
+            .map(|(opened, pressure)| pressure + most_pressure_within[valves.all() ^ opened])
+            .max()
+            .unwrap_or_default();
+
+        Ok(total_pressure_released)
+    }
+}
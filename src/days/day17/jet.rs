@@ -0,0 +1,34 @@
+use std::{fmt, str::FromStr};
+
+use crate::parse::{ParseError, ParseErrorKind};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Jet {
+    Left = -1,
+    Right = 1,
+}
+
+impl FromStr for Jet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "<" => Ok(Self::Left),
+            ">" => Ok(Self::Right),
+            _ => Err(ParseError::new(
+                ParseErrorKind::Unexpected("either `<` or `>`"),
+                s,
+                s,
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Jet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Left => "left",
+            Self::Right => "right",
+        })
+    }
+}
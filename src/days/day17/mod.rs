@@ -0,0 +1,58 @@
+mod jet;
+mod rock;
+mod simulation;
+mod tower;
+
+use crate::{parse::ParseError, AnyResult, Solution};
+pub use jet::Jet;
+pub use rock::Rock;
+use rock::RockShape;
+pub use simulation::{Cell, Chamber, Event, Simulation};
+
+pub struct Day17;
+
+const ROCK_SHAPES: [RockShape; 5] = [
+    RockShape::HorizontalLine,
+    RockShape::Plus,
+    RockShape::ReverseL,
+    RockShape::VerticalLine,
+    RockShape::Square,
+];
+
+impl Solution for Day17 {
+    type Parsed<'a> = Vec<Jet>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(parse_jets(input)?)
+    }
+
+    fn part1(jets: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let mut simulation = Simulation::new(jets);
+
+        simulation.run_with_limit(2022);
+
+        Ok(simulation.highest_y)
+    }
+
+    fn part2(jets: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        Simulation::new(jets).height_after(1_000_000_000_000)
+    }
+}
+
+/// Parses the pattern of jets which push the rocks around as they fall.
+pub fn parse_jets(input: &str) -> Result<Vec<Jet>, ParseError> {
+    let jets = input.trim();
+
+    if jets.is_empty() {
+        return Err(ParseError::missing("a pattern of jets", input));
+    }
+
+    jets.char_indices()
+        .map(|(index, jet)| {
+            let jet = &jets[index..index + jet.len_utf8()];
+
+            jet.parse::<Jet>().map_err(|err| err.rebased(input, jet))
+        })
+        .collect()
+}
@@ -0,0 +1,109 @@
+use super::{tower::Tower, Jet};
+use crate::geometry::Point2;
+
+pub type Position = Point2<u64>;
+
+/// The columns of the chamber blocked by a row of a rock or the tower, with bit `x` set if
+/// column `x` is blocked.
+pub type RowMask = u8;
+
+/// Every column of a row blocked.
+pub const FULL_ROW: RowMask = 0b111_1111;
+
+/// A rock's (or the tower's) masks for 4 rows at once, lowest row in the lowest byte.
+type Rows = u32;
+
+/// A rock which is touching the left wall in any of its rows.
+const LEFT_WALL: Rows = 0x01_01_01_01;
+/// A rock which is touching the right wall in any of its rows.
+const RIGHT_WALL: Rows = 0x40_40_40_40;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RockShape {
+    HorizontalLine,
+    Plus,
+    ReverseL,
+    Square,
+    VerticalLine,
+}
+
+impl RockShape {
+    /// Returns the rows of the shape as it appears, two units away from the left wall.
+    fn rows(self) -> Rows {
+        match self {
+            Self::HorizontalLine => 0x00_00_00_3c,
+            Self::Plus => 0x00_08_1c_08,
+            Self::ReverseL => 0x00_10_10_1c,
+            Self::Square => 0x00_00_0c_0c,
+            Self::VerticalLine => 0x04_04_04_04,
+        }
+    }
+}
+
+pub struct Rock {
+    rows: Rows,
+    /// The height of the rock's lowest row.
+    y: u64,
+}
+
+impl Rock {
+    pub fn new(highest_rock_y: u64, shape: RockShape) -> Self {
+        Self {
+            rows: shape.rows(),
+            y: highest_rock_y + 4,
+        }
+    }
+
+    pub fn try_fall(&mut self, tower: &Tower) -> bool {
+        let can_fall = self.rows & tower.rows(self.y - 1) == 0;
+
+        if can_fall {
+            self.y -= 1;
+        }
+
+        can_fall
+    }
+
+    pub fn try_move_sideways(&mut self, jet: &Jet, tower: &Tower) -> bool {
+        let moved = match jet {
+            Jet::Left if self.rows & LEFT_WALL == 0 => self.rows >> 1,
+            Jet::Right if self.rows & RIGHT_WALL == 0 => self.rows << 1,
+            _ => return false,
+        };
+
+        let can_move_sideways = moved & tower.rows(self.y) == 0;
+
+        if can_move_sideways {
+            self.rows = moved;
+        }
+
+        can_move_sideways
+    }
+
+    /// Returns the masks of each of the rock's rows, from its lowest row up.
+    pub fn row_masks(&self) -> impl Iterator<Item = (u64, RowMask)> + '_ {
+        self.rows
+            .to_le_bytes()
+            .into_iter()
+            .enumerate()
+            .take_while(|&(_, mask)| mask != 0)
+            .map(|(offset, mask)| (self.y + offset as u64, mask))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        self.row_masks().flat_map(|(y, mask)| {
+            (0..7)
+                .filter(move |x| mask & 1 << x != 0)
+                .map(move |x| Position::new(x, y))
+        })
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        self.row_masks()
+            .any(|(y, mask)| y == position.y && mask & 1 << position.x != 0)
+    }
+
+    pub fn top(&self) -> u64 {
+        self.row_masks().last().map_or(self.y, |(y, _)| y)
+    }
+}
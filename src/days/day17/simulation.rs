@@ -0,0 +1,250 @@
+use std::fmt;
+
+use super::{
+    jet::Jet,
+    rock::{Position, Rock, RowMask},
+    tower::Tower,
+    ROCK_SHAPES,
+};
+use crate::{cycle::find_cycle, grid::Grid, AnyResult, CustomError};
+
+/// Gives up looking for the rocks to fall in a repeating pattern after this many rocks, in
+/// case they never do.
+const MAX_CYCLE_ROCKS: usize = 100_000;
+
+/// Everything which decides how the rocks fall from here on: which rock and jet come next, and
+/// the rocks they could still reach or land on.
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct State {
+    rock_index: usize,
+    jet_index: usize,
+    /// The rows of the tower which a falling rock could still reach or land on, lowest first, up
+    /// to the highest rock.
+    reachable_rows: Vec<RowMask>,
+}
+
+/// Something which happens to a rock as it falls, in the order the puzzle describes them.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Event {
+    /// The rock with this index, counting from 0, appears above the tower.
+    Appeared(usize),
+    /// A jet of gas pushed the rock, if nothing was in the way.
+    Pushed {
+        jet: Jet,
+        moved: bool,
+    },
+    Fell,
+    CameToRest,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Appeared(0) => write!(f, "The first rock begins falling:"),
+            Self::Appeared(_) => write!(f, "A new rock begins falling:"),
+            Self::Pushed { jet, moved: true } => write!(f, "Jet of gas pushes rock {jet}:"),
+            Self::Pushed { jet, moved: false } => {
+                write!(f, "Jet of gas pushes rock {jet}, but nothing happens:")
+            }
+            Self::Fell => write!(f, "Rock falls 1 unit:"),
+            Self::CameToRest => write!(f, "Rock falls 1 unit, causing it to come to rest:"),
+        }
+    }
+}
+
+/// What fills one unit of the chamber.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Cell {
+    Air,
+    FallingRock,
+    Rock,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Air => ".",
+            Self::FallingRock => "@",
+            Self::Rock => "#",
+        })
+    }
+}
+
+/// A picture of the top of the chamber, with the highest row first.
+pub struct Chamber {
+    pub cells: Grid<Cell>,
+    /// Whether the picture reaches all the way down to the floor.
+    pub has_floor: bool,
+}
+
+/// Draws the chamber as the puzzle does, between its walls and above its floor.
+impl fmt::Display for Chamber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.rows() {
+            write!(f, "|")?;
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+
+            writeln!(f, "|")?;
+        }
+
+        if self.has_floor {
+            writeln!(f, "+{}+", "-".repeat(self.cells.width()))?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Simulation<'a> {
+    tower: Tower,
+    /// The highest rock in each column, or 0 for the floor if there isn't one.
+    column_tops: [u64; 7],
+    pub highest_y: u64,
+    iteration: usize,
+    jet_index: usize,
+    jets: &'a [Jet],
+}
+
+impl<'a> Simulation<'a> {
+    pub fn new(jets: &'a [Jet]) -> Self {
+        Self {
+            tower: Tower::new(),
+            column_tops: Default::default(),
+            highest_y: Default::default(),
+            iteration: Default::default(),
+            jet_index: Default::default(),
+            jets,
+        }
+    }
+
+    fn state(&self) -> State {
+        State {
+            rock_index: self.iteration % ROCK_SHAPES.len(),
+            jet_index: self.jet_index,
+            reachable_rows: self.tower.reachable_rows(),
+        }
+    }
+
+    /// Drops the next rock and lets it fall until it comes to rest.
+    pub fn drop_rock(&mut self) {
+        self.drop_rock_with(|_, _, _| {});
+    }
+
+    /// Like [`Self::drop_rock`], but calls `watch` with the chamber and the falling rock after
+    /// every step the rock takes.
+    pub fn drop_rock_with(&mut self, mut watch: impl FnMut(&Self, &Rock, Event)) {
+        let rock_shape = ROCK_SHAPES[self.iteration % ROCK_SHAPES.len()];
+        let mut rock = Rock::new(self.highest_y, rock_shape);
+
+        watch(self, &rock, Event::Appeared(self.iteration));
+
+        self.iteration += 1;
+
+        loop {
+            let jet = &self.jets[self.jet_index];
+
+            self.jet_index = (self.jet_index + 1) % self.jets.len();
+
+            let moved = rock.try_move_sideways(jet, &self.tower);
+
+            watch(self, &rock, Event::Pushed { jet: *jet, moved });
+
+            if !rock.try_fall(&self.tower) {
+                self.tower.add(&rock);
+
+                for position in rock.positions() {
+                    let column_top = &mut self.column_tops[position.x as usize];
+
+                    *column_top = position.y.max(*column_top);
+                }
+
+                self.highest_y = self.column_tops.into_iter().max().unwrap_or_default();
+
+                watch(self, &rock, Event::CameToRest);
+
+                break;
+            }
+
+            watch(self, &rock, Event::Fell);
+        }
+    }
+
+    /// Draws the top `rows` rows of the chamber, or as much of it as is still kept if `rows` is
+    /// `None`, up to the highest rock or the top of `falling`, whichever is higher.
+    ///
+    /// A rock which has just come to rest is drawn as part of the tower, even if it's given as
+    /// `falling`.
+    pub fn chamber(&self, falling: Option<&Rock>, rows: Option<usize>) -> Chamber {
+        let top = falling.map_or(0, Rock::top).max(self.highest_y);
+        let bottom = rows
+            .map_or(0, |rows| top.saturating_sub(rows as u64))
+            .max(self.tower.lowest_kept() - 1);
+
+        let cells = (bottom + 1..=top)
+            .rev()
+            .flat_map(|y| (0..7).map(move |x| Position::new(x, y)))
+            .map(|position| {
+                if self.tower.is_blocked(position) {
+                    Cell::Rock
+                } else if falling.is_some_and(|rock| rock.contains(position)) {
+                    Cell::FallingRock
+                } else {
+                    Cell::Air
+                }
+            })
+            .collect();
+
+        Chamber {
+            // There are 7 cells in each row.
+            cells: Grid::from_cells(7, cells).unwrap(),
+            has_floor: bottom == 0,
+        }
+    }
+
+    /// Returns how tall the tower would be after `rocks` rocks, without dropping them all, by
+    /// finding where the rocks start to fall in a repeating pattern.
+    ///
+    /// Any rocks which have already been dropped count towards `rocks`. The simulation is used up
+    /// finding the pattern, as the rocks dropped along the way aren't the `rocks`th.
+    pub fn height_after(mut self, rocks: u64) -> AnyResult<u64> {
+        let already_dropped = self.iteration as u64;
+
+        let steps = rocks
+            .checked_sub(already_dropped)
+            .ok_or_else(|| CustomError {
+                msg: format!(
+                    "{already_dropped} rocks have already been dropped, more than {rocks}."
+                )
+                .into(),
+            })?;
+
+        let states = (0..=MAX_CYCLE_ROCKS).map(move |num_dropped| {
+            if num_dropped > 0 {
+                self.drop_rock();
+            }
+
+            (self.state(), self.highest_y)
+        });
+
+        let cycle = find_cycle(states, |(state, _)| state.clone(), |&(_, height)| height).ok_or(
+            CustomError {
+                msg: format!(
+                    "The rocks didn't fall in a repeating pattern within {MAX_CYCLE_ROCKS} rocks."
+                )
+                .into(),
+            },
+        )?;
+
+        // Heights are `u64`s, which any number of cycles fits in.
+        Ok(cycle.extrapolate(steps).unwrap())
+    }
+
+    pub fn run_with_limit(&mut self, limit: usize) {
+        for _ in 0..limit {
+            self.drop_rock();
+        }
+    }
+}
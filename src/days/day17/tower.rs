@@ -0,0 +1,131 @@
+use std::collections::VecDeque;
+
+use super::rock::{Position, Rock, RowMask, FULL_ROW};
+
+/// How many rows are kept before looking for any which can no longer be reached.
+const MIN_ROWS_KEPT: usize = 64;
+
+/// The rocks which have come to rest in the chamber.
+///
+/// Only the rows which a falling rock could still reach, or land on, are kept. Rows further
+/// down are forgotten, and treated as though they were full, as is the floor.
+pub struct Tower {
+    /// The rows which are kept, lowest first.
+    rows: VecDeque<RowMask>,
+    /// The number of rows below those kept, including the floor.
+    num_forgotten: u64,
+    /// How many rows can be kept before looking for any to forget.
+    forget_at: usize,
+}
+
+impl Tower {
+    pub fn new() -> Self {
+        Self {
+            rows: VecDeque::new(),
+            num_forgotten: 1,
+            forget_at: MIN_ROWS_KEPT,
+        }
+    }
+
+    /// Returns the row at height `y`, where the floor is at 0.
+    fn row(&self, y: u64) -> RowMask {
+        match y.checked_sub(self.num_forgotten) {
+            Some(index) => self.rows.get(index as usize).copied().unwrap_or_default(),
+            None => FULL_ROW,
+        }
+    }
+
+    /// Returns the 4 rows from height `y` upwards, packed like a rock's.
+    pub fn rows(&self, y: u64) -> u32 {
+        u32::from_le_bytes([0, 1, 2, 3].map(|offset| self.row(y + offset)))
+    }
+
+    /// Returns the height of the lowest row which is still kept.
+    pub fn lowest_kept(&self) -> u64 {
+        self.num_forgotten
+    }
+
+    pub fn is_blocked(&self, position: Position) -> bool {
+        self.row(position.y) & 1 << position.x != 0
+    }
+
+    pub fn add(&mut self, rock: &Rock) {
+        for (y, mask) in rock.row_masks() {
+            let index = (y - self.num_forgotten) as usize;
+
+            if index >= self.rows.len() {
+                self.rows.resize(index + 1, 0);
+            }
+
+            self.rows[index] |= mask;
+        }
+
+        if self.rows.len() > self.forget_at {
+            self.forget_unreachable_rows();
+
+            self.forget_at = MIN_ROWS_KEPT.max(2 * self.rows.len());
+        }
+    }
+
+    /// Returns the rows which a falling rock could still reach or land on, lowest first, up to
+    /// the highest rock.
+    ///
+    /// Along with which rock and jet come next, these decide how every later rock falls.
+    pub fn reachable_rows(&self) -> Vec<RowMask> {
+        let lowest_reached = self.num_forgotten + self.lowest_reached() as u64;
+        let top = self.num_forgotten + self.rows.len() as u64;
+
+        // The row just below the lowest one reached is included, as a rock could land on it. If
+        // that row has been forgotten, or is the floor, it's full.
+        (lowest_reached - 1..top).map(|y| self.row(y)).collect()
+    }
+
+    /// Forgets the rows below the lowest one which the air above the tower reaches, apart from
+    /// the row just below it, which a rock could still land on.
+    fn forget_unreachable_rows(&mut self) {
+        let num_unreachable = self.lowest_reached().saturating_sub(1);
+
+        self.rows.drain(..num_unreachable);
+        self.num_forgotten += num_unreachable as u64;
+    }
+
+    /// Returns the index of the lowest kept row which the air above the tower reaches, or the
+    /// number of rows kept if even the top row is full.
+    fn lowest_reached(&self) -> usize {
+        let Some(top) = self.rows.len().checked_sub(1) else {
+            return 0;
+        };
+
+        // Every empty cell in the top row is reachable from above it.
+        let mut reached = vec![0; self.rows.len()];
+        let mut stack: Vec<(usize, usize)> = (0..7)
+            .filter(|&x| self.rows[top] & 1 << x == 0)
+            .map(|x| (x, top))
+            .collect();
+
+        while let Some((x, index)) = stack.pop() {
+            if reached[index] & 1 << x != 0 {
+                continue;
+            }
+
+            reached[index] |= 1 << x;
+
+            let neighbors = [
+                (x.wrapping_sub(1), index),
+                (x + 1, index),
+                (x, index.wrapping_sub(1)),
+                (x, index + 1),
+            ];
+
+            stack.extend(neighbors.into_iter().filter(|&(x, index)| {
+                x < 7 && index < self.rows.len() && self.rows[index] & 1 << x == 0
+            }));
+        }
+
+        // If even the top row is full, only the air above it is reachable.
+        reached
+            .iter()
+            .position(|&mask| mask != 0)
+            .unwrap_or(self.rows.len())
+    }
+}
@@ -0,0 +1,115 @@
+use std::fmt::Write;
+
+use super::Position;
+use crate::hash::FastHashMap;
+
+/// A mesh of square faces which share their corners with the faces next to them, for writing
+/// out in a format 3D viewers can open.
+#[derive(Clone, Debug, Default)]
+pub struct Mesh {
+    pub vertices: Vec<Position>,
+    /// The corners of each face as indices into `vertices`, anticlockwise when seen from the
+    /// front.
+    pub faces: Vec<[usize; 4]>,
+}
+
+impl Mesh {
+    /// Builds a mesh from faces of unit cubes, each given as the cube it belongs to and the
+    /// neighboring cube which it faces.
+    pub fn from_faces(faces: impl IntoIterator<Item = (Position, Position)>) -> Self {
+        let mut mesh = Self::default();
+        let mut vertex_indices: FastHashMap<Position, usize> = FastHashMap::default();
+
+        for (cube, neighbor) in faces {
+            let corners = face_corners(cube, neighbor - cube).map(|corner| {
+                *vertex_indices.entry(corner).or_insert_with(|| {
+                    mesh.vertices.push(corner);
+                    mesh.vertices.len() - 1
+                })
+            });
+
+            mesh.faces.push(corners);
+        }
+
+        mesh
+    }
+
+    /// Writes the mesh as a Wavefront OBJ file.
+    pub fn to_obj(&self) -> String {
+        let mut obj = String::new();
+
+        // Writing to a `String` never fails.
+        for vertex in &self.vertices {
+            writeln!(obj, "v {} {} {}", vertex.x, vertex.y, vertex.z).unwrap();
+        }
+
+        // OBJ files count vertices from 1.
+        for [a, b, c, d] in &self.faces {
+            writeln!(obj, "f {} {} {} {}", a + 1, b + 1, c + 1, d + 1).unwrap();
+        }
+
+        obj
+    }
+
+    /// Writes the mesh as an ASCII PLY file.
+    pub fn to_ply(&self) -> String {
+        let mut ply = format!(
+            "ply\n\
+             format ascii 1.0\n\
+             element vertex {}\n\
+             property int x\n\
+             property int y\n\
+             property int z\n\
+             element face {}\n\
+             property list uchar int vertex_indices\n\
+             end_header\n",
+            self.vertices.len(),
+            self.faces.len()
+        );
+
+        // Writing to a `String` never fails.
+        for vertex in &self.vertices {
+            writeln!(ply, "{} {} {}", vertex.x, vertex.y, vertex.z).unwrap();
+        }
+
+        for [a, b, c, d] in &self.faces {
+            writeln!(ply, "4 {a} {b} {c} {d}").unwrap();
+        }
+
+        ply
+    }
+}
+
+/// Returns the corners of the face of the unit cube at `cube` which points towards `normal`,
+/// anticlockwise when seen from in front of the face.
+fn face_corners(cube: Position, normal: Position) -> [Position; 4] {
+    let normal = [normal.x, normal.y, normal.z];
+
+    // Neighboring cubes only ever differ along one axis.
+    let axis = normal.iter().position(|&component| component != 0).unwrap();
+
+    // Going around `u` then `v` is anticlockwise when looking back along the positive `axis`,
+    // as they follow on from it in the order x, y, z.
+    let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+
+    let mut corner = [cube.x, cube.y, cube.z];
+
+    if normal[axis] > 0 {
+        corner[axis] += 1;
+    }
+
+    let mut corners = [(0, 0), (1, 0), (1, 1), (0, 1)].map(|(du, dv)| {
+        let mut corner = corner;
+
+        corner[u] += du;
+        corner[v] += dv;
+
+        Position::new(corner[0], corner[1], corner[2])
+    });
+
+    if normal[axis] < 0 {
+        corners.reverse();
+    }
+
+    corners
+}
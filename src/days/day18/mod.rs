@@ -0,0 +1,155 @@
+mod mesh;
+
+use crate::{
+    geometry::{Aabb3, Point3},
+    hash::FastHashSet,
+    parse::{parse_lines, ParseError},
+    search::bfs,
+    AnyResult, Solution,
+};
+pub use mesh::Mesh;
+
+pub struct Day18;
+
+type Position = Point3<i32>;
+
+/// The lava droplet, as the cubes it's made of.
+pub struct Droplet {
+    lava: FastHashSet<Position>,
+    /// The smallest box around the droplet, with a layer of air all the way around it, or
+    /// `None` if there's no lava at all.
+    surroundings: Option<Aabb3<i32>>,
+}
+
+/// A pocket of air trapped inside the droplet.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pocket {
+    /// The number of cubes of air in the pocket.
+    pub size: usize,
+    /// The smallest box around the pocket.
+    pub bounds: Aabb3<i32>,
+}
+
+impl Droplet {
+    /// Returns the air cubes around the droplet which are connected to those outside it.
+    fn exterior(&self) -> FastHashSet<Position> {
+        let Some(surroundings) = self.surroundings else {
+            return FastHashSet::default();
+        };
+
+        let neighbors = |&position: &Position| {
+            position.neighbors6().into_iter().filter(move |neighbor| {
+                surroundings.contains(*neighbor) && !self.lava.contains(neighbor)
+            })
+        };
+
+        // The corner of the surroundings is always air, as they have a layer of it all around.
+        bfs(&neighbors, [surroundings.min], |_| false)
+            .costs()
+            .keys()
+            .copied()
+            .collect()
+    }
+
+    /// Returns the faces of the lava cubes which touch `is_air`, each as the cube it belongs to
+    /// and the air cube it faces.
+    fn faces<'a>(
+        &'a self,
+        mut is_air: impl FnMut(&Position) -> bool + 'a,
+    ) -> impl Iterator<Item = (Position, Position)> + 'a {
+        self.lava
+            .iter()
+            .flat_map(|&cube| cube.neighbors6().map(|neighbor| (cube, neighbor)))
+            .filter(move |(_, neighbor)| !self.lava.contains(neighbor) && is_air(neighbor))
+    }
+
+    /// Returns the number of faces of the lava cubes which aren't against another cube.
+    pub fn surface_area(&self) -> usize {
+        self.faces(|_| true).count()
+    }
+
+    /// Returns the number of faces of the lava cubes which air can reach from outside the
+    /// droplet.
+    pub fn exterior_surface_area(&self) -> usize {
+        let exterior = self.exterior();
+
+        self.faces(|air| exterior.contains(air)).count()
+    }
+
+    /// Returns every pocket of air trapped inside the droplet, ordered by their lowest corners.
+    pub fn pockets(&self) -> Vec<Pocket> {
+        let Some(surroundings) = self.surroundings else {
+            return Vec::new();
+        };
+
+        let exterior = self.exterior();
+        let mut enclosed: FastHashSet<Position> = surroundings
+            .positions()
+            .filter(|position| !self.lava.contains(position) && !exterior.contains(position))
+            .collect();
+
+        let mut pockets = Vec::new();
+
+        while let Some(&start) = enclosed.iter().next() {
+            let neighbors = |&position: &Position| {
+                position
+                    .neighbors6()
+                    .into_iter()
+                    .filter(|neighbor| enclosed.contains(neighbor))
+            };
+
+            let cubes: Vec<Position> = bfs(&neighbors, [start], |_| false)
+                .costs()
+                .keys()
+                .copied()
+                .collect();
+
+            for cube in &cubes {
+                enclosed.remove(cube);
+            }
+
+            pockets.push(Pocket {
+                size: cubes.len(),
+                // Every pocket contains at least the cube it was found from.
+                bounds: Aabb3::from_points(cubes).unwrap(),
+            });
+        }
+
+        pockets.sort_unstable_by_key(|pocket| (pocket.bounds.min, pocket.bounds.max));
+
+        pockets
+    }
+
+    /// Builds a mesh of the droplet's exterior, for viewing in a 3D viewer.
+    pub fn exterior_mesh(&self) -> Mesh {
+        let exterior = self.exterior();
+
+        Mesh::from_faces(self.faces(|air| exterior.contains(air)))
+    }
+}
+
+/// Parses the scan of the droplet, with the position of one cube of lava on each line.
+pub fn parse_droplet(input: &str) -> Result<Droplet, ParseError> {
+    let lava: FastHashSet<Position> = parse_lines(input, str::parse)?.into_iter().collect();
+
+    let surroundings = Aabb3::from_points(lava.iter().copied()).map(|bounds| bounds.expanded(1));
+
+    Ok(Droplet { lava, surroundings })
+}
+
+impl Solution for Day18 {
+    type Parsed<'a> = Droplet;
+    type Answer = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(parse_droplet(input)?)
+    }
+
+    fn part1(droplet: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        Ok(droplet.surface_area())
+    }
+
+    fn part2(droplet: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        Ok(droplet.exterior_surface_area())
+    }
+}
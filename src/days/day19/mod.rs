@@ -0,0 +1,327 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    parse::{parse_number, ParseError, ParseErrorKind},
+    AnyResult, Solution,
+};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+pub struct Day19;
+
+// Blueprints are written across several lines in the puzzle's example, so any whitespace is
+// allowed between sentences.
+static BLUEPRINT_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new("^Blueprint (?P<id>\\d+):\\s+Each ore robot costs (?P<ore_robot_ore>\\d+) ore\\.\\s+Each clay robot costs (?P<clay_robot_ore>\\d+) ore\\.\\s+Each obsidian robot costs (?P<obsidian_robot_ore>\\d+) ore and (?P<obsidian_robot_clay>\\d+) clay\\.\\s+Each geode robot costs (?P<geode_robot_ore>\\d+) ore and (?P<geode_robot_obsidian>\\d+) obsidian\\.$").unwrap()
+});
+
+/// Something robots can collect, and the kind of robot which collects it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Resource {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+impl Resource {
+    pub const ALL: [Self; 4] = [Self::Ore, Self::Clay, Self::Obsidian, Self::Geode];
+}
+
+impl fmt::Display for Resource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Ore => "ore",
+            Self::Clay => "clay",
+            Self::Obsidian => "obsidian",
+            Self::Geode => "geode",
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Blueprint {
+    pub id: u32,
+    /// The amount of each resource needed to build each kind of robot, indexed by
+    /// [`Resource`] as `costs[robot][resource]`.
+    costs: [[u32; 4]; 4],
+}
+
+/// Starting to build a robot during the given minute, counted from 1.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Build {
+    pub minute: u32,
+    pub robot: Resource,
+}
+
+/// The order of robots to build which opens the most geodes with a blueprint.
+#[derive(Clone, Debug)]
+pub struct Plan {
+    pub blueprint_id: u32,
+    pub minutes: u32,
+    pub geodes: u32,
+    pub builds: Vec<Build>,
+}
+
+#[derive(Clone, Copy)]
+struct State {
+    minutes_remaining: u32,
+    robots: [u32; 4],
+    resources: [u32; 4],
+    /// Every geode which will have been opened by the time is up, counting those which the
+    /// geode robots built so far are yet to open.
+    geodes: u32,
+}
+
+impl Blueprint {
+    /// Returns the most geodes which can be opened in the given number of minutes.
+    pub fn max_geodes(&self, minutes: u32) -> u32 {
+        self.plan(minutes).geodes
+    }
+
+    /// Searches for the order of robots to build which opens the most geodes in the given
+    /// number of minutes.
+    pub fn plan(&self, minutes: u32) -> Plan {
+        // Only one robot can be built per minute, so having more robots collecting a resource
+        // than the most of it any robot costs would never be useful. Geode robots are the
+        // exception, as geodes are never spent.
+        let mut max_robots = [u32::MAX; 4];
+
+        for resource in [Resource::Ore, Resource::Clay, Resource::Obsidian] {
+            max_robots[resource as usize] = self
+                .costs
+                .iter()
+                .map(|costs| costs[resource as usize])
+                .max()
+                .unwrap_or_default();
+        }
+
+        let mut plan = Plan {
+            blueprint_id: self.id,
+            minutes,
+            geodes: 0,
+            builds: Vec::new(),
+        };
+
+        let start = State {
+            minutes_remaining: minutes,
+            robots: [1, 0, 0, 0],
+            resources: [0; 4],
+            geodes: 0,
+        };
+
+        self.search(start, &max_robots, &mut Vec::new(), &mut plan);
+
+        plan
+    }
+
+    /// Tries building each kind of robot next from `state`, waiting until it can be afforded,
+    /// and keeps `best` up to date with the best plan found so far.
+    fn search(
+        &self,
+        state: State,
+        max_robots: &[u32; 4],
+        builds: &mut Vec<Build>,
+        best: &mut Plan,
+    ) {
+        if state.geodes > best.geodes {
+            best.geodes = state.geodes;
+            best.builds.clone_from(builds);
+        }
+
+        // Even building a geode robot every remaining minute couldn't beat the best plan.
+        let remaining = state.minutes_remaining;
+
+        if state.geodes + remaining * remaining.saturating_sub(1) / 2 <= best.geodes {
+            return;
+        }
+
+        // Geode robots are tried first, so that good plans are found early and prune more.
+        for robot in Resource::ALL.into_iter().rev() {
+            if state.robots[robot as usize] >= max_robots[robot as usize] {
+                continue;
+            }
+
+            let Some(wait) = self.minutes_until_affordable(robot, &state) else {
+                continue;
+            };
+
+            // A robot finished with no time left can't collect anything.
+            let Some(minutes_remaining) = remaining.checked_sub(wait + 1).filter(|&m| m > 0) else {
+                continue;
+            };
+
+            let mut next = State {
+                minutes_remaining,
+                ..state
+            };
+
+            for resource in Resource::ALL {
+                let index = resource as usize;
+
+                next.resources[index] += state.robots[index] * (wait + 1);
+                next.resources[index] -= self.costs[robot as usize][index];
+            }
+
+            if robot == Resource::Geode {
+                next.geodes += minutes_remaining;
+            } else {
+                next.robots[robot as usize] += 1;
+            }
+
+            builds.push(Build {
+                minute: best.minutes - remaining + wait + 1,
+                robot,
+            });
+
+            self.search(next, max_robots, builds, best);
+
+            builds.pop();
+        }
+    }
+
+    /// Returns how many minutes must pass before `robot` can be afforded, or `None` if nothing
+    /// is collecting one of the resources it needs.
+    fn minutes_until_affordable(&self, robot: Resource, state: &State) -> Option<u32> {
+        let mut wait = 0;
+
+        for (index, &cost) in self.costs[robot as usize].iter().enumerate() {
+            let shortfall = cost.saturating_sub(state.resources[index]);
+
+            if shortfall > 0 {
+                let robots = state.robots[index];
+
+                if robots == 0 {
+                    return None;
+                }
+
+                wait = wait.max(shortfall.div_ceil(robots));
+            }
+        }
+
+        Some(wait)
+    }
+}
+
+impl FromStr for Blueprint {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let captures = BLUEPRINT_REGEX.captures(s).ok_or_else(|| {
+            ParseError::new(
+                ParseErrorKind::Unexpected(
+                    "a blueprint like `Blueprint 1: Each ore robot costs 4 ore. Each clay robot \
+                     costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot \
+                     costs 2 ore and 7 obsidian.`",
+                ),
+                s,
+                s,
+            )
+        })?;
+
+        // Every group is always present when the regex matches.
+        let capture_to_u32 = |name| parse_number::<u32>(s, captures.name(name).unwrap().as_str());
+
+        let mut costs = [[0; 4]; 4];
+
+        costs[Resource::Ore as usize][Resource::Ore as usize] = capture_to_u32("ore_robot_ore")?;
+        costs[Resource::Clay as usize][Resource::Ore as usize] = capture_to_u32("clay_robot_ore")?;
+        costs[Resource::Obsidian as usize][Resource::Ore as usize] =
+            capture_to_u32("obsidian_robot_ore")?;
+        costs[Resource::Obsidian as usize][Resource::Clay as usize] =
+            capture_to_u32("obsidian_robot_clay")?;
+        costs[Resource::Geode as usize][Resource::Ore as usize] =
+            capture_to_u32("geode_robot_ore")?;
+        costs[Resource::Geode as usize][Resource::Obsidian as usize] =
+            capture_to_u32("geode_robot_obsidian")?;
+
+        Ok(Self {
+            id: capture_to_u32("id")?,
+            costs,
+        })
+    }
+}
+
+/// Parses every blueprint in `input`, each of which starts with `Blueprint` and may span
+/// several lines.
+pub fn parse_blueprints(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let starts: Vec<usize> = input
+        .match_indices("Blueprint")
+        .map(|(index, _)| index)
+        .collect();
+
+    let before_first = input[..starts.first().copied().unwrap_or(input.len())].trim();
+
+    if !before_first.is_empty() {
+        return Err(ParseError::new(
+            ParseErrorKind::Unexpected("a blueprint"),
+            input,
+            before_first,
+        ));
+    }
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(index, &start)| {
+            let end = starts.get(index + 1).copied().unwrap_or(input.len());
+            let text = input[start..end].trim_end();
+
+            text.parse()
+                .map_err(|err: ParseError| err.rebased(input, text))
+        })
+        .collect()
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Blueprint {} opens {} geode(s) in {} minutes",
+            self.blueprint_id, self.geodes, self.minutes
+        )?;
+
+        for build in &self.builds {
+            let article = match build.robot {
+                Resource::Ore | Resource::Obsidian => "an",
+                Resource::Clay | Resource::Geode => "a",
+            };
+
+            write!(
+                f,
+                "\n  minute {:>2}: build {article} {} robot",
+                build.minute, build.robot
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Solution for Day19 {
+    type Parsed<'a> = Vec<Blueprint>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(parse_blueprints(input)?)
+    }
+
+    fn part1(blueprints: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let total_quality_level = blueprints
+            .iter()
+            .map(|blueprint| blueprint.id * blueprint.max_geodes(24))
+            .sum();
+
+        Ok(total_quality_level)
+    }
+
+    fn part2(blueprints: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let product = blueprints
+            .iter()
+            .take(3)
+            .map(|blueprint| blueprint.max_geodes(32))
+            .product();
+
+        Ok(product)
+    }
+}
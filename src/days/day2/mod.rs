@@ -0,0 +1,183 @@
+use std::str::FromStr;
+
+use crate::{
+    parse::{parse_lines, split_once, ParseError, ParseErrorKind},
+    AnyResult, Solution,
+};
+
+/// One of the three choices in the game Rock, Paper, Scissors
+#[derive(Eq, PartialEq)]
+pub enum HandShape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl HandShape {
+    /// Returns the score you are guaranteed to receive in the Elf tournament, just from
+    /// selecting this hand shape.
+    fn base_score(&self) -> u32 {
+        match self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+
+    /// Returns the hand shape which this hand shape loses against when selected.
+    fn loses_against(&self) -> Self {
+        match self {
+            Self::Rock => Self::Paper,
+            Self::Paper => Self::Scissors,
+            Self::Scissors => Self::Rock,
+        }
+    }
+
+    /// Returns the hand shape which this hand shape wins against when selected.
+    fn wins_against(&self) -> Self {
+        match self {
+            Self::Rock => Self::Scissors,
+            Self::Paper => Self::Rock,
+            Self::Scissors => Self::Paper,
+        }
+    }
+}
+
+impl FromStr for HandShape {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "A" => Ok(Self::Rock),
+            "B" => Ok(Self::Paper),
+            "C" => Ok(Self::Scissors),
+            other => Err(ParseError::new(
+                ParseErrorKind::Unexpected("one of `A`, `B` or `C`"),
+                value,
+                other,
+            )),
+        }
+    }
+}
+
+/// The second column of the strategy guide, whose meaning differs between the two parts.
+#[derive(Clone, Copy)]
+pub enum SecondColumn {
+    X,
+    Y,
+    Z,
+}
+
+impl FromStr for SecondColumn {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "X" => Ok(Self::X),
+            "Y" => Ok(Self::Y),
+            "Z" => Ok(Self::Z),
+            other => Err(ParseError::new(
+                ParseErrorKind::Unexpected("one of `X`, `Y` or `Z`"),
+                value,
+                other,
+            )),
+        }
+    }
+}
+
+pub struct Day2;
+
+/// Splits a game descriptor into the opponent's hand shape and the second column.
+fn parse_game(game: &str) -> Result<(HandShape, SecondColumn), ParseError> {
+    let (opponent_shape, second_column) = split_once(game, game, " ")?;
+
+    Ok((
+        opponent_shape
+            .parse()
+            .map_err(|err: ParseError| err.rebased(game, opponent_shape))?,
+        second_column
+            .parse()
+            .map_err(|err: ParseError| err.rebased(game, second_column))?,
+    ))
+}
+
+impl Solution for Day2 {
+    type Parsed<'a> = Vec<(HandShape, SecondColumn)>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(parse_lines(input, parse_game)?)
+    }
+
+    fn part1(games: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        /// The `Game` struct represents a single Rock Paper Scissors showdown in the tournament.
+        struct Game<'a> {
+            player1: &'a HandShape,
+            player2: HandShape,
+        }
+
+        impl Game<'_> {
+            /// Returns the player score for this game. As we are assumed to be
+            /// player 2 in each game, this is from player 2's perspective.
+            fn score(&self) -> u32 {
+                if self.player2.wins_against() == *self.player1 {
+                    6
+                } else if *self.player1 == self.player2 {
+                    3
+                } else {
+                    0
+                }
+            }
+        }
+
+        let total_score = games
+            .iter()
+            // Read the second column as the hand shape we should play
+            .map(|(player1, second_column)| Game {
+                player1,
+                player2: match second_column {
+                    SecondColumn::X => HandShape::Rock,
+                    SecondColumn::Y => HandShape::Paper,
+                    SecondColumn::Z => HandShape::Scissors,
+                },
+            })
+            // Add the base score and game score
+            .map(|game| game.player2.base_score() + game.score())
+            // Sum the score of each game
+            .sum();
+
+        Ok(total_score)
+    }
+
+    fn part2(games: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        enum GameResult {
+            Win,
+            Draw,
+            Loss,
+        }
+
+        let total_score = games
+            .iter()
+            // Add the base score and the game score
+            .map(|(opponent_shape, second_column)| {
+                // Read the second column as how the game should end
+                let game_result = match second_column {
+                    SecondColumn::X => GameResult::Loss,
+                    SecondColumn::Y => GameResult::Draw,
+                    SecondColumn::Z => GameResult::Win,
+                };
+
+                let (base_score, game_score) = match game_result {
+                    GameResult::Win => (opponent_shape.loses_against().base_score(), 6),
+                    GameResult::Draw => (opponent_shape.base_score(), 3),
+                    GameResult::Loss => (opponent_shape.wins_against().base_score(), 0),
+                };
+
+                base_score + game_score
+            })
+            // Sum the score of each game
+            .sum();
+
+        Ok(total_score)
+    }
+}
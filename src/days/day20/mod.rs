@@ -0,0 +1,167 @@
+use crate::{
+    parse::{parse_lines, parse_number, ParseError},
+    AnyResult, Solution,
+};
+
+pub struct Day20;
+
+const DECRYPTION_KEY: i64 = 811_589_153;
+
+/// The numbers of an encrypted file, in their circular order, which can each be moved around
+/// the circle in `O(√n)` time.
+///
+/// The numbers are split into blocks of roughly `√n` of them. Finding, removing and inserting a
+/// number only touches a single block after skipping past whole blocks, and the blocks are
+/// evened out again every `√n` moves so that none of them grow too large.
+struct Mixer<'a> {
+    numbers: &'a [i64],
+    /// Indices into `numbers`, in their current order.
+    blocks: Vec<Vec<usize>>,
+    /// The block each number is currently in.
+    block_of: Vec<usize>,
+    block_size: usize,
+    moves_since_rebalance: usize,
+}
+
+impl<'a> Mixer<'a> {
+    fn new(numbers: &'a [i64]) -> Self {
+        let block_size = numbers.len().isqrt().max(1);
+
+        let mut mixer = Self {
+            numbers,
+            blocks: Vec::new(),
+            block_of: vec![0; numbers.len()],
+            block_size,
+            moves_since_rebalance: 0,
+        };
+
+        mixer.rebalance((0..numbers.len()).collect());
+
+        mixer
+    }
+
+    /// Splits `order` back into evenly sized blocks.
+    fn rebalance(&mut self, order: Vec<usize>) {
+        self.blocks = order
+            .chunks(self.block_size)
+            .map(<[usize]>::to_vec)
+            .collect();
+
+        for (block_index, block) in self.blocks.iter().enumerate() {
+            for &index in block {
+                self.block_of[index] = block_index;
+            }
+        }
+
+        self.moves_since_rebalance = 0;
+    }
+
+    /// Returns the indices of the numbers in their current order.
+    fn order(&self) -> Vec<usize> {
+        self.blocks.concat()
+    }
+
+    /// Moves every number, in the order they appeared in the file, forward or backward around
+    /// the circle by its own value.
+    fn mix(&mut self) {
+        for index in 0..self.numbers.len() {
+            self.move_number(index);
+        }
+    }
+
+    fn move_number(&mut self, index: usize) {
+        // Once a number is taken out, moving it all the way around the others brings it back to
+        // where it started.
+        let num_others = self.numbers.len() as i64 - 1;
+
+        if num_others == 0 {
+            return;
+        }
+
+        let block_index = self.block_of[index];
+        let block = &mut self.blocks[block_index];
+
+        // Every number is in the block recorded for it.
+        let position_in_block = block.iter().position(|&other| other == index).unwrap();
+        block.remove(position_in_block);
+
+        let position: usize = self.blocks[..block_index]
+            .iter()
+            .map(Vec::len)
+            .sum::<usize>()
+            + position_in_block;
+
+        let mut new_position =
+            (position as i64 + self.numbers[index]).rem_euclid(num_others) as usize;
+
+        // Insert into the first block which reaches the new position, rather than at the start
+        // of the next one, so that moving to the very end doesn't run past the last block.
+        let mut new_block_index = 0;
+
+        while new_position > self.blocks[new_block_index].len() {
+            new_position -= self.blocks[new_block_index].len();
+            new_block_index += 1;
+        }
+
+        self.blocks[new_block_index].insert(new_position, index);
+        self.block_of[index] = new_block_index;
+
+        self.moves_since_rebalance += 1;
+
+        if self.moves_since_rebalance >= self.block_size {
+            self.rebalance(self.order());
+        }
+    }
+}
+
+/// Mixes the numbers the given number of times, then returns the sum of the numbers 1000, 2000
+/// and 3000 places after the 0.
+fn grove_coordinates(numbers: &[i64], rounds: usize) -> i64 {
+    let mut mixer = Mixer::new(numbers);
+
+    for _ in 0..rounds {
+        mixer.mix();
+    }
+
+    let mixed: Vec<i64> = mixer
+        .order()
+        .into_iter()
+        .map(|index| numbers[index])
+        .collect();
+
+    // Parsing checked that there is a 0.
+    let zero_position = mixed.iter().position(|&number| number == 0).unwrap();
+
+    [1000, 2000, 3000]
+        .into_iter()
+        .map(|offset| mixed[(zero_position + offset) % mixed.len()])
+        .sum()
+}
+
+impl Solution for Day20 {
+    type Parsed<'a> = Vec<i64>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        let numbers: Vec<i64> = parse_lines(input, |line| parse_number(line, line))?;
+
+        if !numbers.contains(&0) {
+            return Err(Box::new(ParseError::missing("the number 0", input)));
+        }
+
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        Ok(grove_coordinates(numbers, 1))
+    }
+
+    fn part2(numbers: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let decrypted: Vec<i64> = numbers
+            .iter()
+            .map(|&number| number * DECRYPTION_KEY)
+            .collect();
+
+        Ok(grove_coordinates(&decrypted, 10))
+    }
+}
@@ -0,0 +1,339 @@
+use std::collections::HashMap;
+
+use crate::{
+    parse::{parse_lines, parse_number, split_once, ParseError, ParseErrorKind},
+    AnyResult, CustomError, Solution,
+};
+
+pub struct Day21;
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operator {
+    fn parse(source: &str, text: &str) -> Result<Self, ParseError> {
+        match text {
+            "+" => Ok(Self::Add),
+            "-" => Ok(Self::Subtract),
+            "*" => Ok(Self::Multiply),
+            "/" => Ok(Self::Divide),
+            _ => Err(ParseError::new(
+                ParseErrorKind::Unexpected("one of `+`, `-`, `*` or `/`"),
+                source,
+                text,
+            )),
+        }
+    }
+
+    fn apply(self, left: i64, right: i64) -> Option<i64> {
+        match self {
+            Self::Add => left.checked_add(right),
+            Self::Subtract => left.checked_sub(right),
+            Self::Multiply => left.checked_mul(right),
+            Self::Divide => (right != 0 && left % right == 0).then(|| left / right),
+        }
+    }
+
+    /// Returns the left operand which gives `result` when combined with `right`.
+    fn solve_left(self, result: i64, right: i64) -> Option<i64> {
+        match self {
+            Self::Add => result.checked_sub(right),
+            Self::Subtract => result.checked_add(right),
+            Self::Multiply => Self::Divide.apply(result, right),
+            Self::Divide => result.checked_mul(right),
+        }
+    }
+
+    /// Returns the right operand which gives `result` when combined with `left`.
+    fn solve_right(self, result: i64, left: i64) -> Option<i64> {
+        match self {
+            Self::Add => result.checked_sub(left),
+            Self::Subtract => left.checked_sub(result),
+            Self::Multiply => Self::Divide.apply(result, left),
+            Self::Divide => Self::Divide.apply(left, result),
+        }
+    }
+}
+
+/// What a monkey yells, referring to other monkeys by their index.
+#[derive(Clone, Copy, Debug)]
+enum Job {
+    Number(i64),
+    Operation(usize, Operator, usize),
+}
+
+/// Every monkey's job, as a graph which is checked to have no cycles.
+pub struct Riddle<'a> {
+    names: Vec<&'a str>,
+    jobs: Vec<Job>,
+    /// Every monkey's index, ordered so that each monkey comes after the monkeys it waits on.
+    order: Vec<usize>,
+    root: usize,
+}
+
+impl<'a> Riddle<'a> {
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|&other| other == name)
+    }
+
+    /// Returns the number every monkey yells, or `None` for those whose number depends on
+    /// what the `unknown` monkey yells.
+    fn evaluate(&self, unknown: Option<usize>) -> AnyResult<Vec<Option<i64>>> {
+        let mut values = vec![None; self.jobs.len()];
+
+        for &index in &self.order {
+            if Some(index) == unknown {
+                continue;
+            }
+
+            values[index] = match self.jobs[index] {
+                Job::Number(number) => Some(number),
+                Job::Operation(left, operator, right) => match (values[left], values[right]) {
+                    (Some(left), Some(right)) => {
+                        Some(operator.apply(left, right).ok_or_else(|| {
+                            self.error(index, "yells a number which isn't an integer")
+                        })?)
+                    }
+                    _ => None,
+                },
+            };
+        }
+
+        Ok(values)
+    }
+
+    /// Returns the number the human must yell for both sides of the root monkey's job to be
+    /// equal, found by undoing each operation between the root monkey and the human.
+    fn solve_for_human(&self) -> AnyResult<i64> {
+        let human = self.index_of(HUMAN).ok_or(CustomError {
+            msg: format!("There is no `{HUMAN}` in the riddle.").into(),
+        })?;
+
+        let values = self.evaluate(Some(human))?;
+
+        let Job::Operation(left, _, right) = self.jobs[self.root] else {
+            return Err(self.error(self.root, "must compare two other monkeys"));
+        };
+
+        let (mut unknown, mut target) = self.split_unknown(left, right, &values)?;
+
+        while unknown != human {
+            let Job::Operation(left, operator, right) = self.jobs[unknown] else {
+                unreachable!("Only operations can depend on `{HUMAN}`, other than itself.");
+            };
+
+            let (next, known) = self.split_unknown(left, right, &values)?;
+
+            let solved = if next == left {
+                operator.solve_left(target, known)
+            } else {
+                operator.solve_right(target, known)
+            };
+
+            target = solved.ok_or_else(|| self.error(unknown, "can't yield an integer answer"))?;
+            unknown = next;
+        }
+
+        Ok(target)
+    }
+
+    /// Returns which of `left` and `right` depends on the human, along with the value of the
+    /// other one.
+    fn split_unknown(
+        &self,
+        left: usize,
+        right: usize,
+        values: &[Option<i64>],
+    ) -> AnyResult<(usize, i64)> {
+        match (values[left], values[right]) {
+            (None, Some(right_value)) => Ok((left, right_value)),
+            (Some(left_value), None) => Ok((right, left_value)),
+            (None, None) => Err(Box::new(CustomError {
+                msg: format!(
+                    "`{HUMAN}` appears on both sides of `{}` and `{}`, so it can't be solved for \
+                     by undoing one operation at a time.",
+                    self.names[left], self.names[right]
+                )
+                .into(),
+            })),
+            (Some(_), Some(_)) => Err(Box::new(CustomError {
+                msg: format!(
+                    "Neither `{}` nor `{}` depends on `{HUMAN}`.",
+                    self.names[left], self.names[right]
+                )
+                .into(),
+            })),
+        }
+    }
+
+    fn error(&self, index: usize, problem: &str) -> Box<CustomError> {
+        Box::new(CustomError {
+            msg: format!("`{}` {problem}.", self.names[index]).into(),
+        })
+    }
+}
+
+/// A monkey's job as written, before the monkeys it waits on have been looked up.
+enum UnresolvedJob<'a> {
+    Number(i64),
+    Operation(&'a str, Operator, &'a str),
+}
+
+fn parse_monkey(line: &str) -> Result<(&str, UnresolvedJob<'_>), ParseError> {
+    let (name, job) = split_once(line, line, ": ")?;
+
+    let job = match job.split(' ').collect::<Vec<_>>()[..] {
+        [number] => UnresolvedJob::Number(parse_number(line, number)?),
+        [left, operator, right] => {
+            UnresolvedJob::Operation(left, Operator::parse(line, operator)?, right)
+        }
+        _ => {
+            return Err(ParseError::new(
+                ParseErrorKind::Unexpected("a number or an operation like `pppw + sjmn`"),
+                line,
+                job,
+            ))
+        }
+    };
+
+    Ok((name, job))
+}
+
+/// Orders the monkeys so that each comes after every monkey it waits on, or returns the index
+/// of a monkey which ends up waiting on itself.
+fn order_jobs(jobs: &[Job]) -> Result<Vec<usize>, usize> {
+    let mut num_waiting_on = vec![0; jobs.len()];
+    let mut waited_on_by = vec![Vec::new(); jobs.len()];
+
+    for (index, job) in jobs.iter().enumerate() {
+        if let &Job::Operation(left, _, right) = job {
+            num_waiting_on[index] = 2;
+            waited_on_by[left].push(index);
+            waited_on_by[right].push(index);
+        }
+    }
+
+    let mut order: Vec<usize> = (0..jobs.len())
+        .filter(|&index| num_waiting_on[index] == 0)
+        .collect();
+
+    let mut next = 0;
+
+    while let Some(&index) = order.get(next) {
+        for &waiting in &waited_on_by[index] {
+            num_waiting_on[waiting] -= 1;
+
+            if num_waiting_on[waiting] == 0 {
+                order.push(waiting);
+            }
+        }
+
+        next += 1;
+    }
+
+    let Some(mut index) = num_waiting_on.iter().position(|&count| count > 0) else {
+        return Ok(order);
+    };
+
+    // The monkey found may only be waiting on a cycle rather than be part of one, but following
+    // whichever monkey it's still waiting on for as many steps as there are monkeys must end up
+    // inside a cycle.
+    for _ in 0..jobs.len() {
+        if let Job::Operation(left, _, right) = jobs[index] {
+            index = if num_waiting_on[left] > 0 {
+                left
+            } else {
+                right
+            };
+        }
+    }
+
+    Err(index)
+}
+
+impl<'a> TryFrom<&'a str> for Riddle<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let monkeys = parse_lines(input, parse_monkey)?;
+
+        let mut indices: HashMap<&str, usize> = HashMap::new();
+
+        for (index, &(name, _)) in monkeys.iter().enumerate() {
+            if indices.insert(name, index).is_some() {
+                return Err(ParseError::new(
+                    ParseErrorKind::Unexpected("every monkey to have a different name"),
+                    input,
+                    name,
+                ));
+            }
+        }
+
+        let resolve = |name: &str| {
+            indices.get(name).copied().ok_or_else(|| {
+                ParseError::new(
+                    ParseErrorKind::Unexpected("the name of a monkey with a job"),
+                    input,
+                    name,
+                )
+            })
+        };
+
+        let jobs = monkeys
+            .iter()
+            .map(|(_, job)| match *job {
+                UnresolvedJob::Number(number) => Ok(Job::Number(number)),
+                UnresolvedJob::Operation(left, operator, right) => {
+                    Ok(Job::Operation(resolve(left)?, operator, resolve(right)?))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let names: Vec<&str> = monkeys.iter().map(|&(name, _)| name).collect();
+
+        let order = order_jobs(&jobs).map_err(|index| {
+            ParseError::new(
+                ParseErrorKind::Unexpected("no monkey to end up waiting on itself"),
+                input,
+                names[index],
+            )
+        })?;
+
+        let root = *indices
+            .get(ROOT)
+            .ok_or_else(|| ParseError::missing("a job for `root`", input))?;
+
+        Ok(Self {
+            names,
+            jobs,
+            order,
+            root,
+        })
+    }
+}
+
+impl Solution for Day21 {
+    type Parsed<'a> = Riddle<'a>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(Riddle::try_from(input)?)
+    }
+
+    fn part1(riddle: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        // Nothing is unknown, so every monkey has a number.
+        Ok(riddle.evaluate(None)?[riddle.root].unwrap())
+    }
+
+    fn part2(riddle: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        riddle.solve_for_human()
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use super::Tile;
+use crate::{
+    geometry::{Direction, Point2, Point3},
+    grid::Grid,
+    AnyResult, CustomError,
+};
+
+type Vector = Point3<i32>;
+
+/// How a face of the net lies on the cube: the directions in 3D which the face's own right and
+/// down point in, and the direction pointing out of the cube through it.
+#[derive(Clone, Copy, Debug)]
+struct Orientation {
+    right: Vector,
+    down: Vector,
+    normal: Vector,
+}
+
+impl Orientation {
+    /// Returns the direction in 3D which `direction` points in on this face.
+    fn axis(&self, direction: Direction) -> Vector {
+        match direction {
+            Direction::Up => -self.down,
+            Direction::Right => self.right,
+            Direction::Down => self.down,
+            Direction::Left => -self.right,
+        }
+    }
+
+    /// Returns the orientation of the face joined to this one's edge in `direction`, which
+    /// folds down over that edge.
+    fn rolled(&self, direction: Direction) -> Self {
+        let axis = self.axis(direction);
+
+        let mut rolled = Self {
+            normal: axis,
+            ..*self
+        };
+
+        // The axis which pointed over the edge now points into the cube.
+        match direction {
+            Direction::Up => rolled.down = self.normal,
+            Direction::Right => rolled.right = -self.normal,
+            Direction::Down => rolled.down = -self.normal,
+            Direction::Left => rolled.right = self.normal,
+        }
+
+        rolled
+    }
+}
+
+fn dot(a: Vector, b: Vector) -> i32 {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+/// The board folded up into a cube, which lets a path carry on over the edges between faces.
+pub struct Cube {
+    /// The length of each face's edges, in tiles.
+    size: i32,
+    /// How each face lies on the cube, keyed by its position in the net, counted in faces.
+    faces: HashMap<Point2<i32>, Orientation>,
+}
+
+impl Cube {
+    /// Folds the board up along the edges between its faces, which works for any of the 11
+    /// nets of a cube.
+    pub fn fold(tiles: &Grid<Tile>) -> AnyResult<Self> {
+        let not_a_net = || {
+            Box::new(CustomError {
+                msg: "The board isn't the net of a cube.".into(),
+            })
+        };
+
+        let num_tiles = tiles.iter().filter(|(_, &tile)| tile != Tile::Void).count();
+        let size = (num_tiles / 6).isqrt();
+
+        if size == 0 || num_tiles != 6 * size * size {
+            return Err(not_a_net());
+        }
+
+        // Every tile of a face is on the board, so checking each face's corner finds them all,
+        // then checking every tile lands in a face makes sure none are missing.
+        let face_positions: Vec<Point2<i32>> = tiles
+            .positions()
+            .filter(|&(x, y)| x % size == 0 && y % size == 0 && tiles[(x, y)] != Tile::Void)
+            .map(|(x, y)| Point2::new((x / size) as i32, (y / size) as i32))
+            .collect();
+
+        let faces_are_whole = face_positions.iter().all(|face| {
+            (0..size * size).all(|index| {
+                let x = face.x as usize * size + index % size;
+                let y = face.y as usize * size + index / size;
+
+                tiles.get((x, y)).is_some_and(|&tile| tile != Tile::Void)
+            })
+        });
+
+        if face_positions.len() != 6 || !faces_are_whole {
+            return Err(not_a_net());
+        }
+
+        let mut faces = HashMap::from([(
+            face_positions[0],
+            Orientation {
+                right: Vector::new(1, 0, 0),
+                down: Vector::new(0, 1, 0),
+                normal: Vector::new(0, 0, 1),
+            },
+        )]);
+
+        let mut unfolded = vec![face_positions[0]];
+
+        while let Some(face) = unfolded.pop() {
+            let orientation = faces[&face];
+
+            for direction in Direction::ALL {
+                let neighbor = face + direction.step();
+
+                if face_positions.contains(&neighbor) && !faces.contains_key(&neighbor) {
+                    faces.insert(neighbor, orientation.rolled(direction));
+                    unfolded.push(neighbor);
+                }
+            }
+        }
+
+        // A net which folds two faces onto the same side of the cube leaves another side open.
+        let mut normals: Vec<Vector> = faces.values().map(|face| face.normal).collect();
+        normals.sort();
+        normals.dedup();
+
+        if normals.len() != 6 {
+            return Err(not_a_net());
+        }
+
+        Ok(Self {
+            size: size as i32,
+            faces,
+        })
+    }
+
+    /// Returns the tile reached by stepping over the edge of the face `position` is on, in
+    /// `direction`, along with the direction which is then being faced.
+    pub fn wrap(&self, position: Point2<i32>, direction: Direction) -> (Point2<i32>, Direction) {
+        let size = self.size;
+
+        let face = Point2::new(position.x.div_euclid(size), position.y.div_euclid(size));
+        let within_face = position - face * size;
+
+        let from = self.faces[&face];
+        let heading = from.axis(direction);
+
+        // Positions are measured from the centre of the cube in half tiles, which keeps the
+        // centre of every tile on a whole number.
+        let centre = from.normal * size
+            + from.right * (2 * within_face.x - (size - 1))
+            + from.down * (2 * within_face.y - (size - 1));
+
+        // Tipping over the edge moves half a tile along the heading onto the next face, and
+        // half a tile back down from the face being left.
+        let wrapped_centre = centre + heading - from.normal;
+
+        // Folding the net made sure there's a face on every side of the cube.
+        let (&to_face, to) = self
+            .faces
+            .iter()
+            .find(|(_, face)| face.normal == heading)
+            .unwrap();
+
+        let to_direction = Direction::ALL
+            .into_iter()
+            .find(|&direction| to.axis(direction) == -from.normal)
+            .unwrap();
+
+        let within_to_face = Point2::new(
+            (dot(wrapped_centre, to.right) + size - 1) / 2,
+            (dot(wrapped_centre, to.down) + size - 1) / 2,
+        );
+
+        (to_face * size + within_to_face, to_direction)
+    }
+}
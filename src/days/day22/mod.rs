@@ -0,0 +1,200 @@
+mod cube;
+
+use crate::{
+    geometry::{Direction, Point2},
+    grid::Grid,
+    parse::{parse_number, ParseError, ParseErrorKind},
+    AnyResult, CustomError, Solution,
+};
+use cube::Cube;
+
+pub struct Day22;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tile {
+    /// Off the edge of the board, as marked by the spaces padding each row.
+    Void,
+    Open,
+    Wall,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    Forward(u32),
+    TurnLeft,
+    TurnRight,
+}
+
+pub struct MonkeyMap {
+    /// The board, with every row padded to the same width with [`Tile::Void`].
+    tiles: Grid<Tile>,
+    path: Vec<Instruction>,
+}
+
+impl MonkeyMap {
+    fn tile(&self, position: Point2<i32>) -> Tile {
+        let (Ok(x), Ok(y)) = (usize::try_from(position.x), usize::try_from(position.y)) else {
+            return Tile::Void;
+        };
+
+        self.tiles.get((x, y)).copied().unwrap_or(Tile::Void)
+    }
+
+    /// Follows the path from the leftmost open tile of the top row, calling `wrap` to find where
+    /// each step off the edge of the board leads, and returns the final password.
+    fn walk(
+        &self,
+        wrap: impl Fn(Point2<i32>, Direction) -> (Point2<i32>, Direction),
+    ) -> AnyResult<i32> {
+        let start_x = self
+            .tiles
+            .row(0)
+            .position(|&tile| tile == Tile::Open)
+            .ok_or(CustomError {
+                msg: "The top row of the board has no open tiles.".into(),
+            })?;
+
+        let mut position = Point2::new(start_x as i32, 0);
+        let mut direction = Direction::Right;
+
+        for &instruction in &self.path {
+            match instruction {
+                Instruction::TurnLeft => direction = direction.turn_left(),
+                Instruction::TurnRight => direction = direction.turn_right(),
+                Instruction::Forward(steps) => {
+                    for _ in 0..steps {
+                        let (next_position, next_direction) =
+                            match self.tile(position + direction.step()) {
+                                Tile::Void => wrap(position, direction),
+                                _ => (position + direction.step(), direction),
+                            };
+
+                        if self.tile(next_position) == Tile::Wall {
+                            break;
+                        }
+
+                        (position, direction) = (next_position, next_direction);
+                    }
+                }
+            }
+        }
+
+        // Facings are scored clockwise starting from right, whereas directions start from up.
+        let facing = (direction as i32 + 3) % 4;
+
+        Ok(1000 * (position.y + 1) + 4 * (position.x + 1) + facing)
+    }
+
+    /// Returns the tile reached by leaving the board at `position` in `direction`, then coming
+    /// back onto it from the opposite edge of the same row or column.
+    fn wrap_flat(&self, position: Point2<i32>, direction: Direction) -> (Point2<i32>, Direction) {
+        let back = direction.opposite().step();
+
+        let mut wrapped = position;
+
+        while self.tile(wrapped + back) != Tile::Void {
+            wrapped += back;
+        }
+
+        (wrapped, direction)
+    }
+}
+
+/// Parses a board whose rows may be of different lengths, padding each row with void tiles.
+fn parse_board(input: &str, board: &str) -> Result<Grid<Tile>, ParseError> {
+    let width = board
+        .lines()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let mut tiles = Vec::with_capacity(width * board.lines().count());
+
+    for line in board.lines() {
+        for (index, char) in line.char_indices() {
+            tiles.push(parse_cell(input, line, index, char)?);
+        }
+
+        tiles.resize(tiles.len() + width - line.chars().count(), Tile::Void);
+    }
+
+    // Every row was padded to the same width.
+    Ok(Grid::from_cells(width, tiles).unwrap())
+}
+
+fn parse_cell(input: &str, line: &str, index: usize, char: char) -> Result<Tile, ParseError> {
+    match char {
+        ' ' => Ok(Tile::Void),
+        '.' => Ok(Tile::Open),
+        '#' => Ok(Tile::Wall),
+        _ => Err(ParseError::new(
+            ParseErrorKind::Unexpected("one of ` `, `.` or `#`"),
+            input,
+            &line[index..index + char.len_utf8()],
+        )),
+    }
+}
+
+/// Parses a path like `10R5L5`.
+fn parse_path(input: &str, path: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut instructions = Vec::new();
+    let mut number_start = None;
+
+    for (index, char) in path.char_indices() {
+        if char.is_ascii_digit() {
+            number_start.get_or_insert(index);
+            continue;
+        }
+
+        if let Some(start) = number_start.take() {
+            instructions.push(Instruction::Forward(parse_number(
+                input,
+                &path[start..index],
+            )?));
+        }
+
+        instructions.push(match char {
+            'L' => Instruction::TurnLeft,
+            'R' => Instruction::TurnRight,
+            _ => {
+                return Err(ParseError::new(
+                    ParseErrorKind::Unexpected("a number of steps, `L` or `R`"),
+                    input,
+                    &path[index..index + char.len_utf8()],
+                ))
+            }
+        });
+    }
+
+    if let Some(start) = number_start {
+        instructions.push(Instruction::Forward(parse_number(input, &path[start..])?));
+    }
+
+    Ok(instructions)
+}
+
+impl Solution for Day22 {
+    type Parsed<'a> = MonkeyMap;
+    type Answer = i32;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        let (board, path) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::missing("a blank line between the board and the path", input)
+        })?;
+
+        Ok(MonkeyMap {
+            tiles: parse_board(input, board)?,
+            path: parse_path(input, path.trim_end())?,
+        })
+    }
+
+    fn part1(map: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        map.walk(|position, direction| map.wrap_flat(position, direction))
+    }
+
+    fn part2(map: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let cube = Cube::fold(&map.tiles)?;
+
+        map.walk(|position, direction| cube.wrap(position, direction))
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{
+    geometry::{Aabb2, Direction8, Point2},
+    grid::Grid,
+    hash::{FastHashMap, FastHashSet},
+    AnyResult, CustomError, Solution,
+};
+
+pub struct Day23;
+
+type Position = Point2<i32>;
+
+/// The directions elves consider moving in, in order of preference at the start.
+const PROPOSAL_DIRECTIONS: [Direction8; 4] = [
+    Direction8::Up,
+    Direction8::Down,
+    Direction8::Left,
+    Direction8::Right,
+];
+
+/// Gives up looking for a round with no movement after this many rounds, in case it never
+/// comes.
+const MAX_ROUNDS: usize = 100_000;
+
+/// The elves spreading out across the grove, which can stretch out in every direction.
+struct Simulation {
+    elves: Vec<Position>,
+    occupied: FastHashSet<Position>,
+    /// The index into [`PROPOSAL_DIRECTIONS`] which is considered first this round.
+    first_direction: usize,
+}
+
+impl Simulation {
+    fn new(elves: &[Position]) -> Self {
+        Self {
+            elves: elves.to_vec(),
+            occupied: elves.iter().copied().collect(),
+            first_direction: 0,
+        }
+    }
+
+    /// Returns where the elf at `elf` wants to move to, if it wants to move at all.
+    fn proposal(&self, elf: Position) -> Option<Position> {
+        // The bits of the neighboring tiles which are occupied, in the order of
+        // `Direction8::ALL`.
+        let neighbors = Direction8::ALL
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, direction)| self.occupied.contains(&(elf + direction.step())))
+            .fold(0u8, |neighbors, (index, _)| neighbors | 1 << index);
+
+        if neighbors == 0 {
+            return None;
+        }
+
+        (0..PROPOSAL_DIRECTIONS.len())
+            .map(|offset| PROPOSAL_DIRECTIONS[(self.first_direction + offset) % 4])
+            .find(|&direction| {
+                // The tile in the direction itself, and the two diagonals either side of it.
+                let blocking = [
+                    direction.rotate_anticlockwise(1),
+                    direction,
+                    direction.rotate_clockwise(1),
+                ]
+                .into_iter()
+                .fold(0u8, |blocking, direction| {
+                    blocking | 1 << direction as usize
+                });
+
+                neighbors & blocking == 0
+            })
+            .map(|direction| elf + direction.step())
+    }
+
+    /// Plays out a single round, returning whether any elf moved.
+    fn round(&mut self) -> bool {
+        let proposals: Vec<Option<Position>> =
+            self.elves.iter().map(|&elf| self.proposal(elf)).collect();
+
+        let mut num_proposing: FastHashMap<Position, u32> = FastHashMap::default();
+
+        for &proposal in proposals.iter().flatten() {
+            *num_proposing.entry(proposal).or_default() += 1;
+        }
+
+        let mut any_moved = false;
+
+        for (elf, proposal) in self.elves.iter_mut().zip(proposals) {
+            if let Some(destination) = proposal.filter(|proposal| num_proposing[proposal] == 1) {
+                self.occupied.remove(elf);
+                self.occupied.insert(destination);
+                *elf = destination;
+                any_moved = true;
+            }
+        }
+
+        self.first_direction = (self.first_direction + 1) % PROPOSAL_DIRECTIONS.len();
+
+        any_moved
+    }
+
+    /// Returns the number of empty tiles in the smallest rectangle containing every elf.
+    fn empty_tiles(&self) -> usize {
+        Aabb2::from_points(self.elves.iter().copied()).map_or(0, |bounds| {
+            (bounds.width() * bounds.height()) as usize - self.elves.len()
+        })
+    }
+}
+
+impl Solution for Day23 {
+    type Parsed<'a> = Vec<Position>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        let scan = Grid::parse(
+            input,
+            |char| match char {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            },
+            "`#` or `.`",
+        )?;
+
+        Ok(scan
+            .iter()
+            .filter(|(_, &is_elf)| is_elf)
+            .map(|((x, y), _)| Position::new(x as i32, y as i32))
+            .collect())
+    }
+
+    fn part1(elves: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let mut simulation = Simulation::new(elves);
+
+        for _ in 0..10 {
+            simulation.round();
+        }
+
+        Ok(simulation.empty_tiles())
+    }
+
+    fn part2(elves: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let mut simulation = Simulation::new(elves);
+
+        let first_still_round =
+            (1..=MAX_ROUNDS)
+                .find(|_| !simulation.round())
+                .ok_or(CustomError {
+                    msg: format!("The elves were still moving after {MAX_ROUNDS} rounds.").into(),
+                })?;
+
+        Ok(first_still_round)
+    }
+}
@@ -0,0 +1,185 @@
+use std::str::FromStr;
+
+use crate::{
+    geometry::{Direction, Point2},
+    grid::Grid,
+    parse::ParseError,
+    search::bfs,
+    AnyResult, CustomError, Solution,
+};
+
+pub struct Day24;
+
+type Position = Point2<i32>;
+
+/// The valley between its walls, where the blizzards repeat their pattern every
+/// `lcm(width, height)` minutes.
+pub struct Valley {
+    /// The width of the valley inside its walls.
+    width: i32,
+    /// The height of the valley inside its walls.
+    height: i32,
+    /// Where the blizzards blowing in each direction start out, indexed by `Direction as usize`
+    /// and positioned relative to the top left corner inside the walls.
+    blizzards: [Grid<bool>; 4],
+    entrance: Position,
+    exit: Position,
+}
+
+impl Valley {
+    fn period(&self) -> usize {
+        lcm(self.width as usize, self.height as usize)
+    }
+
+    /// Returns whether any blizzard is over `position` at the given minute.
+    fn has_blizzard(&self, position: Position, minute: usize) -> bool {
+        let (x, y) = (position.x - 1, position.y - 1);
+
+        // Finding where each blizzard which could be here would have started from, rather than
+        // moving every blizzard, means the valley never has to be simulated.
+        let (dx, dy) = (
+            (minute % self.width as usize) as i32,
+            (minute % self.height as usize) as i32,
+        );
+
+        let started_at = |direction: Direction, x: i32, y: i32| {
+            let start = (
+                x.rem_euclid(self.width) as usize,
+                y.rem_euclid(self.height) as usize,
+            );
+
+            self.blizzards[direction as usize][start]
+        };
+
+        started_at(Direction::Right, x - dx, y)
+            || started_at(Direction::Left, x + dx, y)
+            || started_at(Direction::Down, x, y - dy)
+            || started_at(Direction::Up, x, y + dy)
+    }
+
+    fn is_open(&self, position: Position, minute: usize) -> bool {
+        if position == self.entrance || position == self.exit {
+            return true;
+        }
+
+        let inside =
+            (1..=self.width).contains(&position.x) && (1..=self.height).contains(&position.y);
+
+        inside && !self.has_blizzard(position, minute)
+    }
+
+    /// Returns the fewest minutes needed to get from `from` to `to`, setting off at
+    /// `start_minute`.
+    fn crossing_time(&self, from: Position, to: Position, start_minute: usize) -> AnyResult<usize> {
+        let period = self.period();
+
+        // Minutes are only tracked modulo the period, which is all the blizzards depend on.
+        let neighbors = |&(position, minute): &(Position, usize)| {
+            let next_minute = (minute + 1) % period;
+
+            Direction::ALL
+                .into_iter()
+                .map(move |direction| position + direction.step())
+                .chain([position])
+                .filter(move |&next| self.is_open(next, next_minute))
+                .map(move |next| (next, next_minute))
+        };
+
+        let minutes = bfs(
+            &neighbors,
+            [(from, start_minute % period)],
+            |&(position, _)| position == to,
+        )
+        .goal_cost()
+        .ok_or(CustomError {
+            msg: "There is no way through the blizzards.".into(),
+        })?;
+
+        Ok(minutes)
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+impl FromStr for Valley {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse(
+            s,
+            |char| matches!(char, '#' | '.' | '>' | '<' | '^' | 'v').then_some(char),
+            "one of `#`, `.`, `>`, `<`, `^` or `v`",
+        )?;
+
+        if map.width() < 3 || map.height() < 3 {
+            return Err(ParseError::missing("a valley inside the walls", s));
+        }
+
+        let entrance = map
+            .row(0)
+            .position(|&char| char == '.')
+            .ok_or_else(|| ParseError::missing("a gap in the top wall", s))?;
+
+        let exit = map
+            .row(map.height() - 1)
+            .position(|&char| char == '.')
+            .ok_or_else(|| ParseError::missing("a gap in the bottom wall", s))?;
+
+        let (width, height) = (map.width() - 2, map.height() - 2);
+
+        let blizzards = Direction::ALL.map(|direction| {
+            let arrow = match direction {
+                Direction::Up => '^',
+                Direction::Right => '>',
+                Direction::Down => 'v',
+                Direction::Left => '<',
+            };
+
+            let cells = (0..width * height)
+                .map(|index| map[(index % width + 1, index / width + 1)] == arrow)
+                .collect();
+
+            // There is exactly one cell per position inside the walls.
+            Grid::from_cells(width, cells).unwrap()
+        });
+
+        Ok(Self {
+            width: width as i32,
+            height: height as i32,
+            blizzards,
+            entrance: Position::new(entrance as i32, 0),
+            exit: Position::new(exit as i32, map.height() as i32 - 1),
+        })
+    }
+}
+
+impl Solution for Day24 {
+    type Parsed<'a> = Valley;
+    type Answer = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(valley: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        valley.crossing_time(valley.entrance, valley.exit, 0)
+    }
+
+    fn part2(valley: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let there = valley.crossing_time(valley.entrance, valley.exit, 0)?;
+        let back = valley.crossing_time(valley.exit, valley.entrance, there)?;
+        let there_again = valley.crossing_time(valley.entrance, valley.exit, there + back)?;
+
+        Ok(there + back + there_again)
+    }
+}
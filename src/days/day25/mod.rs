@@ -0,0 +1,26 @@
+use crate::{parse::parse_lines, snafu::Snafu, AnyResult, CustomError, Solution};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed<'a> = Vec<Snafu>;
+    type Answer = String;
+
+    // Day 25 only has one puzzle, so there is nothing to solve for the second star.
+    const HAS_PART2: bool = false;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(parse_lines(input, str::parse)?)
+    }
+
+    fn part1(fuel_requirements: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        Ok(fuel_requirements.iter().sum::<Snafu>().to_string())
+    }
+
+    /// Never called, as there is no second part.
+    fn part2(_: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        Err(Box::new(CustomError {
+            msg: "Day 25 only has one puzzle.".into(),
+        }))
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+
+use crate::{
+    parse::{parse_lines, ParseError, ParseErrorKind},
+    AnyResult, CustomError, Solution,
+};
+
+pub struct Day3;
+
+/// The items in a rucksack, each an ASCII letter, with the same number in each of its two
+/// compartments.
+#[derive(Clone, Copy, Debug)]
+pub struct Rucksack<'a> {
+    items: &'a str,
+}
+
+impl<'a> Rucksack<'a> {
+    fn compartments(&self) -> (&'a str, &'a str) {
+        self.items.split_at(self.items.len() / 2)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Rucksack<'a> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        if s.is_empty() {
+            return Err(ParseError::missing("the items in a rucksack", s));
+        }
+
+        if let Some((index, char)) = s
+            .char_indices()
+            .find(|(_, char)| !char.is_ascii_alphabetic())
+        {
+            return Err(ParseError::new(
+                ParseErrorKind::Unexpected(
+                    "an item, which is a letter from `a` to `z` or `A` to `Z`",
+                ),
+                s,
+                &s[index..index + char.len_utf8()],
+            ));
+        }
+
+        if !s.len().is_multiple_of(2) {
+            return Err(ParseError::new(
+                ParseErrorKind::Unexpected("an even number of items, half in each compartment"),
+                s,
+                s,
+            ));
+        }
+
+        Ok(Self { items: s })
+    }
+}
+
+impl Solution for Day3 {
+    type Parsed<'a> = Vec<Rucksack<'a>>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(parse_lines(input, Rucksack::try_from)?)
+    }
+
+    fn part1(rucksacks: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let priority_sum = rucksacks
+            .iter()
+            // Split the rucksack into 2 compartments, and convert the first into a set
+            .map(|rucksack| {
+                let (first, second) = rucksack.compartments();
+
+                let first_compartment_item_types: HashSet<_> = first.chars().collect();
+
+                (second, first_compartment_item_types)
+            })
+            .map(find_duplicate_item_type)
+            .map(|duplicate| duplicate.map(get_priority))
+            .sum::<Result<u32, _>>()?;
+
+        Ok(priority_sum)
+    }
+
+    fn part2(rucksacks: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let mut rucksacks: Vec<&str> = rucksacks.iter().map(|rucksack| rucksack.items).collect();
+
+        let priority_sum: u32 = rucksacks
+            .chunks_exact_mut(3)
+            // Converts the shortest 2 rucksacks into sets, takes their intersection,
+            // then iterates through the longest until it finds a duplicate
+            .map(|squad_rucksacks| {
+                squad_rucksacks.sort_unstable_by_key(|rucksack| rucksack.len());
+
+                let first_item_types: HashSet<_> = squad_rucksacks[0].chars().collect();
+                let second_item_types: HashSet<_> = squad_rucksacks[1].chars().collect();
+
+                let shared_item_types: HashSet<_> = first_item_types
+                    .intersection(&second_item_types)
+                    .copied()
+                    .collect();
+
+                (squad_rucksacks[2], shared_item_types)
+            })
+            .map(find_duplicate_item_type)
+            .map(|duplicate| duplicate.map(get_priority))
+            .sum::<Result<u32, _>>()?;
+
+        Ok(priority_sum)
+    }
+}
+
+/// Returns the first item from `container` which is in `already_seen_items`,
+/// or an error if none exists.
+fn find_duplicate_item_type(
+    (container, already_seen_items): (&str, HashSet<char>),
+) -> Result<char, CustomError> {
+    container
+        .chars()
+        .find(|char| already_seen_items.contains(char))
+        .ok_or(CustomError {
+            msg: "No duplicate item was detected.".into(),
+        })
+}
+
+/// Returns the priority value of the given item, which parsing checks is an ASCII letter.
+fn get_priority(item: char) -> u32 {
+    if item.is_uppercase() {
+        (item as u32) - ('A' as u32) + 27
+    } else {
+        (item as u32) - ('a' as u32) + 1
+    }
+}
@@ -0,0 +1,90 @@
+use std::ops::RangeInclusive;
+
+use crate::{
+    parse::{parse_lines, parse_number, split_once, ParseError},
+    AnyResult, Solution,
+};
+
+type StrPair<'a> = (&'a str, &'a str);
+
+/// Returns a pair of sections, or an error if there was no `,` character in the line.
+fn get_section_pair(line: &str) -> Result<StrPair<'_>, ParseError> {
+    split_once(line, line, ",")
+}
+
+/// Returns 2 start/end pairs of boundaries for ranges from a section pair,
+/// or an error if there was no `-` character in either section.
+fn section_pair_to_range_boundaries<'a>(
+    line: &str,
+    (first, second): StrPair<'a>,
+) -> Result<(StrPair<'a>, StrPair<'a>), ParseError> {
+    Ok((
+        split_once(line, first, "-")?,
+        split_once(line, second, "-")?,
+    ))
+}
+
+/// Returns a pair of inclusive ranges, corresponding to the provided boundaries.
+fn range_boundaries_to_ranges(
+    line: &str,
+    ((first_start, first_end), (second_start, second_end)): (StrPair, StrPair),
+) -> Result<SectionRanges, ParseError> {
+    Ok((
+        parse_number(line, first_start)?..=parse_number(line, first_end)?,
+        parse_number(line, second_start)?..=parse_number(line, second_end)?,
+    ))
+}
+
+type SectionRanges = (RangeInclusive<u16>, RangeInclusive<u16>);
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed<'a> = Vec<SectionRanges>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(parse_lines(input, |line| {
+            let section_pair = get_section_pair(line)?;
+            let range_boundaries = section_pair_to_range_boundaries(line, section_pair)?;
+
+            range_boundaries_to_ranges(line, range_boundaries)
+        })?)
+    }
+
+    fn part1(range_pairs: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let num_overlapping_sections = range_pairs
+            .iter()
+            // Filter out ranges which don't have the shortest contained by the longest on both ends
+            .filter(|(first_range, second_range)| {
+                if first_range.len() > second_range.len() {
+                    first_range.contains(second_range.start())
+                        && first_range.contains(second_range.end())
+                } else {
+                    second_range.contains(first_range.start())
+                        && second_range.contains(first_range.end())
+                }
+            })
+            .count();
+
+        Ok(num_overlapping_sections)
+    }
+
+    fn part2(range_pairs: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let num_overlapping_sections = range_pairs
+            .iter()
+            // Filter out ranges which don't have the shortest contained by the longest on either end
+            .filter(|(first_range, second_range)| {
+                if first_range.len() > second_range.len() {
+                    first_range.contains(second_range.start())
+                        || first_range.contains(second_range.end())
+                } else {
+                    second_range.contains(first_range.start())
+                        || second_range.contains(first_range.end())
+                }
+            })
+            .count();
+
+        Ok(num_overlapping_sections)
+    }
+}
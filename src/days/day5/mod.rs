@@ -0,0 +1,179 @@
+use once_cell::sync::Lazy;
+
+use crate::{
+    parse::{parse_number, ParseError, ParseErrorKind},
+    AnyResult, Solution,
+};
+use regex::{Captures, Regex};
+
+static COMMAND_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new("move (?P<amount>\\d+) from (?P<source>\\d) to (?P<destination>\\d)").unwrap()
+});
+
+#[derive(Clone, Debug)]
+pub struct Crate {
+    label: char,
+}
+
+type Command = (usize, usize, usize);
+
+pub struct Day5;
+
+/// Builds the stacks of crates from the drawing at the top of the input. The last line
+/// of the drawing labels each stack, so is only used to count them.
+fn initial_cargo_bay(drawing: &str) -> Result<Vec<Vec<Crate>>, ParseError> {
+    let mut lines: Vec<_> = drawing.lines().collect();
+
+    let stack_labels = lines
+        .pop()
+        .ok_or_else(|| ParseError::missing("a drawing of the starting stacks", drawing))?;
+
+    let num_stacks = stack_labels.split_whitespace().count();
+
+    Ok(lines
+        .into_iter()
+        .rev()
+        .flat_map(|line| line.chars().skip(1).step_by(4).enumerate())
+        .fold(
+            vec![vec![]; num_stacks],
+            |mut cargo_bay, (stack_index, label)| {
+                if label != ' ' {
+                    cargo_bay[stack_index].push(Crate { label });
+                }
+
+                cargo_bay
+            },
+        ))
+}
+
+/// Parses a named capture group, which is always present when the command regex matches.
+fn capture_group_to_usize(
+    command: &str,
+    captures: &Captures,
+    name: &str,
+) -> Result<usize, ParseError> {
+    parse_number(command, &captures[name])
+}
+
+/// Parses a one-indexed stack number into an index into our `Vec`, checking that the stack
+/// was drawn.
+fn capture_group_to_stack_index(
+    command: &str,
+    captures: &Captures,
+    name: &str,
+    num_stacks: usize,
+) -> Result<usize, ParseError> {
+    let stack_number = capture_group_to_usize(command, captures, name)?;
+
+    if stack_number == 0 {
+        return Err(ParseError::new(
+            ParseErrorKind::Unexpected("a stack number of at least 1"),
+            command,
+            &captures[name],
+        ));
+    }
+
+    if stack_number > num_stacks {
+        return Err(ParseError::new(
+            ParseErrorKind::Unexpected("the number of a stack in the drawing"),
+            command,
+            &captures[name],
+        ));
+    }
+
+    Ok(stack_number - 1)
+}
+
+/// Parses a command, checking it against the height of each stack before it runs, which are
+/// then updated to after it. Both parts move the same number of crates between the same stacks,
+/// so the heights are the same for either.
+fn command_to_values(command: &str, stack_heights: &mut [usize]) -> Result<Command, ParseError> {
+    let captures = COMMAND_REGEX.captures(command).ok_or_else(|| {
+        ParseError::new(
+            ParseErrorKind::Unexpected("a command like `move 1 from 2 to 3`"),
+            command,
+            command,
+        )
+    })?;
+
+    let num_stacks = stack_heights.len();
+
+    let amount = capture_group_to_usize(command, &captures, "amount")?;
+    let source = capture_group_to_stack_index(command, &captures, "source", num_stacks)?;
+    let destination = capture_group_to_stack_index(command, &captures, "destination", num_stacks)?;
+
+    if amount > stack_heights[source] {
+        return Err(ParseError::new(
+            ParseErrorKind::Unexpected("no more crates than are on the stack they move from"),
+            command,
+            &captures["amount"],
+        ));
+    }
+
+    stack_heights[source] -= amount;
+    stack_heights[destination] += amount;
+
+    Ok((amount, source, destination))
+}
+
+fn tops_of_stacks(cargo_bay: &[Vec<Crate>]) -> String {
+    cargo_bay
+        .iter()
+        .filter_map(|stack| stack.last().map(|cargo_crate| cargo_crate.label))
+        .collect()
+}
+
+impl Solution for Day5 {
+    type Parsed<'a> = (Vec<Vec<Crate>>, Vec<Command>);
+    type Answer = String;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        let (drawing, commands) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::missing("a blank line between the drawing and the commands", input)
+        })?;
+
+        let cargo_bay = initial_cargo_bay(drawing).map_err(|err| err.rebased(input, drawing))?;
+
+        let mut stack_heights: Vec<usize> = cargo_bay.iter().map(Vec::len).collect();
+
+        let commands = commands
+            .lines()
+            .map(|command| {
+                command_to_values(command, &mut stack_heights)
+                    .map_err(|err| err.rebased(input, command))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok((cargo_bay, commands))
+    }
+
+    fn part1((cargo_bay, commands): &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let mut cargo_bay = cargo_bay.clone();
+
+        // Parsing checks that no command moves more crates than its stack holds.
+        for &(amount, source_index, destination_index) in commands {
+            for _ in 0..amount {
+                let cargo_crate = cargo_bay[source_index].pop().unwrap();
+
+                cargo_bay[destination_index].push(cargo_crate);
+            }
+        }
+
+        Ok(tops_of_stacks(&cargo_bay))
+    }
+
+    fn part2((cargo_bay, commands): &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let mut cargo_bay = cargo_bay.clone();
+
+        // Parsing checks that no command moves more crates than its stack holds.
+        for &(amount, source_index, destination_index) in commands {
+            let source = &mut cargo_bay[source_index];
+
+            let mut crates_to_be_moved = source.split_off(source.len() - amount);
+
+            cargo_bay[destination_index].append(&mut crates_to_be_moved);
+        }
+
+        Ok(tops_of_stacks(&cargo_bay))
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+
+use crate::{AnyResult, CustomError, Solution};
+
+pub struct Day6;
+
+fn find_unique_window_index(datastream: &[u8], size: usize) -> Option<usize> {
+    datastream
+        .windows(size)
+        .enumerate()
+        .find_map(|(index, window)| {
+            let mut seen_values = HashSet::with_capacity(size);
+
+            window
+                .iter()
+                .all(|value| seen_values.insert(value))
+                .then_some(index + size)
+        })
+}
+
+impl Solution for Day6 {
+    type Parsed<'a> = &'a [u8];
+    type Answer = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(input.trim().as_bytes())
+    }
+
+    fn part1(datastream: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let start_of_packet_marker =
+            find_unique_window_index(datastream, 4).ok_or(CustomError {
+                msg: "No valid start-of-packet marker detected.".into(),
+            })?;
+
+        Ok(start_of_packet_marker)
+    }
+
+    fn part2(datastream: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let start_of_message_marker =
+            find_unique_window_index(datastream, 14).ok_or(CustomError {
+                msg: "No valid start-of-message marker detected.".into(),
+            })?;
+
+        Ok(start_of_message_marker)
+    }
+}
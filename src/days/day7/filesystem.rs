@@ -0,0 +1,489 @@
+use std::fmt;
+
+use indextree::{Arena, NodeId};
+
+use crate::{
+    hash::{FastHashMap, FastHashSet},
+    parse::{split_once, ParseError, ParseErrorKind},
+};
+
+pub enum FileSystemEntity<'a> {
+    File(&'a str, usize),
+    Directory(&'a str),
+}
+
+impl<'a> FileSystemEntity<'a> {
+    pub fn name(&self) -> &'a str {
+        match self {
+            Self::File(name, _) | Self::Directory(name) => name,
+        }
+    }
+}
+
+pub struct Entry<'a> {
+    pub entity: FileSystemEntity<'a>,
+    /// The size of the file, or the total size of every file anywhere inside the directory.
+    pub size: usize,
+}
+
+/// Something about the commands in the terminal output which doesn't add up, though the file
+/// system can still be pieced together.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Problem<'a> {
+    /// The line the problem is on, counting from 1.
+    pub line: usize,
+    /// The text on that line which the problem is with.
+    pub text: &'a str,
+    pub kind: ProblemKind,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProblemKind {
+    /// The directory at this path was listed for a second time, so the repeated listing is
+    /// ignored rather than counting its files twice.
+    RepeatedListing { path: String, first_line: usize },
+    /// A `cd` into a directory which no `ls` of the parent at this path had shown, so it's
+    /// assumed to be there.
+    UnknownDirectory { parent_path: String },
+}
+
+impl Problem<'_> {
+    /// Returns the error which parsing the day's input fails with because of this problem.
+    pub fn to_parse_error(&self, input: &str) -> ParseError {
+        let expected = match self.kind {
+            ProblemKind::RepeatedListing { .. } => "a directory which hasn't been listed already",
+            ProblemKind::UnknownDirectory { .. } => "a directory listed by an earlier `ls`",
+        };
+
+        ParseError::new(ParseErrorKind::Unexpected(expected), input, self.text)
+    }
+}
+
+impl fmt::Display for Problem<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ProblemKind::RepeatedListing { path, first_line } => write!(
+                f,
+                "line {}: `ls` lists {path} again, after already listing it on line {first_line}",
+                self.line
+            ),
+            ProblemKind::UnknownDirectory { parent_path } => write!(
+                f,
+                "line {}: `cd` into `{}`, which no `ls` of {parent_path} had shown",
+                self.line, self.text
+            ),
+        }
+    }
+}
+
+enum Command<'a> {
+    ChangeDir(CdArgument<'a>),
+    List,
+}
+
+enum CdArgument<'a> {
+    DirectoryName(&'a str),
+    ParentDirectory,
+    Root,
+}
+
+impl<'a> TryFrom<&'a str> for Command<'a> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        match s {
+            "$ ls" => Ok(Command::List),
+            "$ cd .." => Ok(Command::ChangeDir(CdArgument::ParentDirectory)),
+            "$ cd /" => Ok(Command::ChangeDir(CdArgument::Root)),
+            other => other
+                .strip_prefix("$ cd ")
+                .map(|directory_name| Command::ChangeDir(CdArgument::DirectoryName(directory_name)))
+                .ok_or_else(|| {
+                    ParseError::new(
+                        ParseErrorKind::Unexpected("either `$ ls` or `$ cd <directory>`"),
+                        s,
+                        other,
+                    )
+                }),
+        }
+    }
+}
+
+/// The file system pieced together from the output of the commands run in the terminal, with
+/// the size of every directory worked out up front.
+pub struct FileSystem<'a> {
+    arena: Arena<Entry<'a>>,
+    root: NodeId,
+    problems: Vec<Problem<'a>>,
+}
+
+impl<'a> FileSystem<'a> {
+    pub fn root(&self) -> NodeId {
+        self.root
+    }
+
+    pub fn entry(&self, id: NodeId) -> &Entry<'a> {
+        self.arena[id].get()
+    }
+
+    /// Returns anything about the commands which didn't add up, in the order they were run.
+    pub fn problems(&self) -> &[Problem<'a>] {
+        &self.problems
+    }
+
+    /// Returns every directory, starting with the root, with each directory before the ones
+    /// inside it.
+    pub fn directories(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.root
+            .descendants(&self.arena)
+            .filter(|&id| matches!(self.entry(id).entity, FileSystemEntity::Directory(_)))
+    }
+
+    fn child(&self, id: NodeId, name: &str) -> Option<NodeId> {
+        id.children(&self.arena)
+            .find(|&child_id| self.entry(child_id).entity.name() == name)
+    }
+
+    fn child_directory(&self, id: NodeId, name: &str) -> Option<NodeId> {
+        self.child(id, name).filter(|&child_id| {
+            matches!(self.entry(child_id).entity, FileSystemEntity::Directory(_))
+        })
+    }
+
+    /// Returns the full path of an entry, such as `/a/e/i`.
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names: Vec<&str> = id
+            .ancestors(&self.arena)
+            .take_while(|&ancestor_id| ancestor_id != self.root)
+            .map(|ancestor_id| self.entry(ancestor_id).entity.name())
+            .collect();
+
+        if names.is_empty() {
+            return "/".into();
+        }
+
+        names.reverse();
+
+        names.iter().map(|name| format!("/{name}")).collect()
+    }
+
+    /// Returns the entry at the given path, such as `/a/e/i`, which is followed from the root
+    /// whether or not it starts with `/`. Both `.` and `..` are understood.
+    pub fn resolve(&self, path: &str) -> Option<NodeId> {
+        path.split('/')
+            .filter(|&component| !component.is_empty() && component != ".")
+            .try_fold(self.root, |id, component| {
+                if component == ".." {
+                    Some(self.arena[id].parent().unwrap_or(self.root))
+                } else {
+                    self.child(id, component)
+                }
+            })
+    }
+
+    /// Returns every entry whose path matches a glob `pattern`, such as `/a/*/i` or `**/*.txt`,
+    /// in the order they appear in the tree.
+    ///
+    /// Within a name, `*` matches any run of characters and `?` matches any one character,
+    /// while a `**` component matches any number of directories.
+    pub fn glob(&self, pattern: &str) -> Vec<NodeId> {
+        let components: Vec<&str> = pattern
+            .split('/')
+            .filter(|component| !component.is_empty())
+            .collect();
+
+        let mut matches = FastHashSet::default();
+
+        self.glob_from(self.root, &components, &mut matches);
+
+        self.root
+            .descendants(&self.arena)
+            .filter(|id| matches.contains(id))
+            .collect()
+    }
+
+    fn glob_from(&self, id: NodeId, components: &[&str], matches: &mut FastHashSet<NodeId>) {
+        let Some((&component, rest)) = components.split_first() else {
+            matches.insert(id);
+            return;
+        };
+
+        if component == "**" {
+            self.glob_from(id, rest, matches);
+
+            for child_id in id.children(&self.arena) {
+                self.glob_from(child_id, components, matches);
+            }
+        } else {
+            for child_id in id.children(&self.arena) {
+                if matches_wildcards(component, self.entry(child_id).entity.name()) {
+                    self.glob_from(child_id, rest, matches);
+                }
+            }
+        }
+    }
+
+    /// Returns the path and size of every directory no more than `max_depth` directories below
+    /// the root, or every directory at all, biggest first, like `du --max-depth`.
+    pub fn disk_usage(&self, max_depth: Option<usize>) -> Vec<(String, usize)> {
+        let mut usage: Vec<(String, usize)> = self
+            .directories()
+            .filter(|&id| max_depth.is_none_or(|max_depth| self.depth(id) <= max_depth))
+            .map(|id| (self.path(id), self.entry(id).size))
+            .collect();
+
+        usage.sort_unstable_by(|(path, size), (other_path, other_size)| {
+            other_size.cmp(size).then_with(|| path.cmp(other_path))
+        });
+
+        usage
+    }
+
+    /// Returns how many directories below the root an entry is.
+    fn depth(&self, id: NodeId) -> usize {
+        id.ancestors(&self.arena).count() - 1
+    }
+
+    fn fmt_children(&self, f: &mut fmt::Formatter<'_>, id: NodeId, indent: &str) -> fmt::Result {
+        let mut children = id.children(&self.arena).peekable();
+
+        while let Some(child_id) = children.next() {
+            let is_last = children.peek().is_none();
+            let Entry { entity, size } = self.entry(child_id);
+
+            let branch = if is_last { "└── " } else { "├── " };
+            let slash = match entity {
+                FileSystemEntity::Directory(_) => "/",
+                FileSystemEntity::File(..) => "",
+            };
+
+            writeln!(f, "{indent}{branch}{}{slash} ({size})", entity.name())?;
+
+            let indent = format!("{indent}{}", if is_last { "    " } else { "│   " });
+
+            self.fmt_children(f, child_id, &indent)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Draws the file system like the `tree` command, with the size of everything in brackets.
+impl fmt::Display for FileSystem<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "/ ({})", self.entry(self.root).size)?;
+
+        self.fmt_children(f, self.root, "")
+    }
+}
+
+/// Returns whether `name` matches `pattern`, where `*` matches any run of characters and `?`
+/// matches any one character.
+fn matches_wildcards(pattern: &str, name: &str) -> bool {
+    let (pattern, name): (Vec<char>, Vec<char>) =
+        (pattern.chars().collect(), name.chars().collect());
+
+    let (mut pattern_index, mut name_index) = (0, 0);
+
+    // Where to go back to if what follows the last `*` stops matching, which is to let that `*`
+    // match one more character.
+    let mut backtrack = None;
+
+    while name_index < name.len() {
+        match pattern.get(pattern_index) {
+            Some('*') => {
+                backtrack = Some((pattern_index, name_index));
+                pattern_index += 1;
+            }
+            Some(&char) if char == '?' || char == name[name_index] => {
+                pattern_index += 1;
+                name_index += 1;
+            }
+            _ => match backtrack {
+                Some((star_index, star_name_index)) => {
+                    backtrack = Some((star_index, star_name_index + 1));
+                    pattern_index = star_index + 1;
+                    name_index = star_name_index + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[pattern_index..].iter().all(|&char| char == '*')
+}
+
+/// Formats a size in bytes like `du -h`, in powers of 1024 and rounded up, e.g. `584`, `9.2K` or
+/// `47M`.
+pub fn human_size(size: usize) -> String {
+    const UNITS: [&str; 6] = ["K", "M", "G", "T", "P", "E"];
+
+    if size < 1024 {
+        return size.to_string();
+    }
+
+    let mut scaled = size as f64;
+
+    for unit in UNITS {
+        scaled /= 1024.0;
+
+        let tenths = (scaled * 10.0).ceil() / 10.0;
+
+        if tenths < 10.0 {
+            return format!("{tenths:.1}{unit}");
+        }
+
+        let whole = scaled.ceil();
+
+        if whole < 1024.0 {
+            return format!("{whole}{unit}");
+        }
+    }
+
+    format!("{}E", scaled.ceil())
+}
+
+/// Pieces together the file system from the output of the commands run in the terminal.
+///
+/// Commands which don't add up are noted in [`FileSystem::problems`] rather than failing: a
+/// repeated `ls` is ignored, and a `cd` into a directory no `ls` has shown assumes it exists.
+pub fn parse_file_system(input: &str) -> Result<FileSystem<'_>, ParseError> {
+    let mut lines = input.lines().map(str::trim).zip(1..);
+
+    let (first_line, _) = lines
+        .next()
+        .ok_or_else(|| ParseError::missing("the first command `$ cd /`", input))?;
+
+    if first_line != "$ cd /" {
+        return Err(ParseError::new(
+            ParseErrorKind::Unexpected("the first command to be `$ cd /`"),
+            input,
+            first_line,
+        ));
+    }
+
+    let mut arena = Arena::new();
+
+    let root = arena.new_node(Entry {
+        entity: FileSystemEntity::Directory("/"),
+        size: 0,
+    });
+
+    let mut file_system = FileSystem {
+        arena,
+        root,
+        problems: Vec::new(),
+    };
+
+    let mut current_id = file_system.root;
+
+    // The line each directory was first listed on.
+    let mut listed_on: FastHashMap<NodeId, usize> = FastHashMap::default();
+
+    // Whether the output being read is from a repeated `ls`, so should be skipped.
+    let mut is_repeat_listing = false;
+
+    for (line, line_number) in lines {
+        if line.starts_with('$') {
+            let command = Command::try_from(line).map_err(|err| err.rebased(input, line))?;
+
+            is_repeat_listing = false;
+
+            match command {
+                Command::List => {
+                    if let Some(&first_line) = listed_on.get(&current_id) {
+                        file_system.problems.push(Problem {
+                            line: line_number,
+                            text: line,
+                            kind: ProblemKind::RepeatedListing {
+                                path: file_system.path(current_id),
+                                first_line,
+                            },
+                        });
+
+                        is_repeat_listing = true;
+                    } else {
+                        listed_on.insert(current_id, line_number);
+                    }
+                }
+                Command::ChangeDir(CdArgument::Root) => current_id = file_system.root,
+                Command::ChangeDir(CdArgument::ParentDirectory) => {
+                    current_id = file_system.arena[current_id].parent().ok_or_else(|| {
+                        ParseError::new(
+                            ParseErrorKind::Unexpected("a parent directory, but this is the root"),
+                            input,
+                            line,
+                        )
+                    })?;
+                }
+                Command::ChangeDir(CdArgument::DirectoryName(directory_name)) => {
+                    current_id = match file_system.child_directory(current_id, directory_name) {
+                        Some(child_id) => child_id,
+                        None => {
+                            file_system.problems.push(Problem {
+                                line: line_number,
+                                text: directory_name,
+                                kind: ProblemKind::UnknownDirectory {
+                                    parent_path: file_system.path(current_id),
+                                },
+                            });
+
+                            let child_id = file_system.arena.new_node(Entry {
+                                entity: FileSystemEntity::Directory(directory_name),
+                                size: 0,
+                            });
+
+                            current_id.append(child_id, &mut file_system.arena);
+
+                            child_id
+                        }
+                    };
+                }
+            }
+        } else {
+            let (descriptor, entity_name) = split_once(input, line, " ")?;
+
+            let entity = if descriptor == "dir" {
+                FileSystemEntity::Directory(entity_name)
+            } else if let Ok(file_size) = descriptor.parse::<usize>() {
+                FileSystemEntity::File(entity_name, file_size)
+            } else {
+                return Err(ParseError::new(
+                    ParseErrorKind::Unexpected("either `dir` or a file size"),
+                    input,
+                    descriptor,
+                ));
+            };
+
+            if is_repeat_listing {
+                continue;
+            }
+
+            let size = match entity {
+                FileSystemEntity::File(_, size) => size,
+                FileSystemEntity::Directory(_) => 0,
+            };
+
+            let new_id = file_system.arena.new_node(Entry { entity, size });
+
+            current_id.append(new_id, &mut file_system.arena);
+        }
+    }
+
+    // Going through the tree backwards reaches everything inside a directory before the
+    // directory itself.
+    let ids: Vec<NodeId> = file_system.root.descendants(&file_system.arena).collect();
+
+    for &id in ids.iter().rev() {
+        if matches!(file_system.entry(id).entity, FileSystemEntity::Directory(_)) {
+            let size = id
+                .children(&file_system.arena)
+                .map(|child_id| file_system.entry(child_id).size)
+                .sum();
+
+            file_system.arena[id].get_mut().size = size;
+        }
+    }
+
+    Ok(file_system)
+}
@@ -0,0 +1,68 @@
+use crate::{AnyResult, CustomError, Solution};
+
+mod filesystem;
+
+pub use filesystem::{
+    human_size, parse_file_system, Entry, FileSystem, FileSystemEntity, Problem, ProblemKind,
+};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed<'a> = FileSystem<'a>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        let file_system = parse_file_system(input)?;
+
+        // The puzzle's terminal output should never need piecing together around a problem.
+        if let Some(problem) = file_system.problems().first() {
+            return Err(Box::new(problem.to_parse_error(input)));
+        }
+
+        Ok(file_system)
+    }
+
+    fn part1(file_system: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let small_directory_size_sum = file_system
+            .directories()
+            .map(|directory_id| file_system.entry(directory_id).size)
+            .filter(|&size| size <= 100_000)
+            .sum();
+
+        Ok(small_directory_size_sum)
+    }
+
+    fn part2(file_system: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let total_disk_space: usize = 70_000_000;
+        let required_disk_space: usize = 30_000_000;
+
+        let root_directory_size = file_system.entry(file_system.root()).size;
+
+        let unused_disk_space = total_disk_space
+            .checked_sub(root_directory_size)
+            .ok_or_else(|| CustomError {
+                msg: format!(
+                    "The files take up {root_directory_size}, more than the disk's \
+                     {total_disk_space}."
+                )
+                .into(),
+            })?;
+
+        // There may already be enough space for the update without deleting anything.
+        let Some(space_to_be_freed) = required_disk_space.checked_sub(unused_disk_space) else {
+            return Ok(0);
+        };
+
+        let smallest_big_directory = file_system
+            .directories()
+            .map(|directory_id| file_system.entry(directory_id).size)
+            .filter(|&size| size > space_to_be_freed)
+            .min()
+            .ok_or(CustomError {
+                msg: "No directory big enough to make space was found.".into(),
+            })?;
+
+        Ok(smallest_big_directory)
+    }
+}
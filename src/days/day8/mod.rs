@@ -0,0 +1,60 @@
+use crate::{geometry::Direction, grid::Grid, AnyResult, CustomError, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed<'a> = Grid<u32>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(Grid::parse(
+            input,
+            |char| char.to_digit(10),
+            "a tree height from 0 to 9",
+        )?)
+    }
+
+    fn part1(grid: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let num_visible_trees: usize = grid
+            .iter()
+            // Trees on the edge have nothing in at least one of their sight lines, so are
+            // always visible
+            .filter(|&(position, height)| {
+                Direction::ALL.into_iter().any(|direction| {
+                    grid.ray(position, direction.step::<isize>().into())
+                        .all(|other_position| grid[other_position] < *height)
+                })
+            })
+            .count();
+
+        Ok(num_visible_trees)
+    }
+
+    fn part2(grid: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let highest_scenic_score = grid
+            .iter()
+            .map(|(position, height)| {
+                Direction::ALL
+                    .into_iter()
+                    .map(|direction| {
+                        let mut count = 0;
+
+                        for other_position in grid.ray(position, direction.step::<isize>().into()) {
+                            count += 1;
+                            if grid[other_position] >= *height {
+                                break;
+                            }
+                        }
+
+                        count
+                    })
+                    .product::<usize>()
+            })
+            .max()
+            .ok_or(CustomError {
+                msg: "Tried to find the max scenic score of an empty grid.".into(),
+            })?;
+
+        Ok(highest_scenic_score)
+    }
+}
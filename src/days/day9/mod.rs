@@ -0,0 +1,108 @@
+use std::{collections::HashSet, str::FromStr};
+
+use crate::{
+    geometry::{Direction, Point2},
+    parse::{parse_lines, parse_number, split_once, ParseError, ParseErrorKind},
+    AnyResult, Solution,
+};
+
+pub struct Day9;
+
+pub struct Motion {
+    direction: Direction,
+    steps: u32,
+}
+
+type Position = Point2<i32>;
+
+/// Returns the step the knot at `tail_position` needs to take to keep up with the knot at
+/// `head_position`, or `None` if they're still touching.
+fn required_catchup_movement(head_position: Position, tail_position: Position) -> Option<Position> {
+    (head_position.chebyshev_distance(tail_position) > 1)
+        .then(|| (head_position - tail_position).signum())
+}
+
+impl FromStr for Motion {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, magnitude) = split_once(s, s, " ")?;
+
+        let steps = parse_number(s, magnitude)?;
+
+        let direction = match direction {
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            "U" => Direction::Up,
+            _ => {
+                return Err(ParseError::new(
+                    ParseErrorKind::Unexpected("one of `U`, `D`, `L` or `R`"),
+                    s,
+                    direction,
+                ))
+            }
+        };
+
+        Ok(Self { direction, steps })
+    }
+}
+
+impl Solution for Day9 {
+    type Parsed<'a> = Vec<Motion>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(parse_lines(input, str::parse)?)
+    }
+
+    fn part1(motions: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let mut visited_positions: HashSet<Position> = HashSet::new();
+
+        let (mut head_position, mut tail_position) = (Position::default(), Position::default());
+
+        for motion in motions {
+            for _ in 0..motion.steps {
+                head_position += motion.direction.step();
+
+                if let Some(movement) = required_catchup_movement(head_position, tail_position) {
+                    tail_position += movement;
+                }
+
+                visited_positions.insert(tail_position);
+            }
+        }
+
+        let num_visited_positions = visited_positions.len();
+
+        Ok(num_visited_positions)
+    }
+
+    fn part2(motions: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        let mut tail_visited_positions: HashSet<Position> = HashSet::new();
+
+        let mut positions = [Position::default(); 10];
+
+        for motion in motions {
+            for _ in 0..motion.steps {
+                positions[0] += motion.direction.step();
+
+                for index in 1..positions.len() {
+                    if let Some(movement) =
+                        required_catchup_movement(positions[index - 1], positions[index])
+                    {
+                        positions[index] += movement;
+                    } else {
+                        break;
+                    }
+                }
+
+                tail_visited_positions.insert(positions[9]);
+            }
+        }
+
+        let num_visited_positions = tail_visited_positions.len();
+
+        Ok(num_visited_positions)
+    }
+}
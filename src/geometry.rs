@@ -0,0 +1,408 @@
+use std::{
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::parse::{parse_number, ParseError, ParseErrorKind};
+
+/// A point, or an offset between two points, on a 2D integer lattice.
+///
+/// Where a direction is involved, `y` grows downward, as it does for the rows of a
+/// [`Grid`](crate::grid::Grid).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point, or an offset between two points, on a 3D integer lattice.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+/// Returns how far apart `a` and `b` are, without needing `T` to be signed.
+fn abs_diff<T: Copy + Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// Returns -1, 0 or 1 depending on the sign of `value`.
+fn signum<T: Ord + From<i8>>(value: T) -> T {
+    T::from(value.cmp(&T::from(0)) as i8)
+}
+
+/// Implements the component-wise operators and helpers shared by both kinds of point.
+macro_rules! impl_point {
+    ($point:ident { $first:ident $(, $rest:ident)* }) => {
+        impl_point!(@ops $point { $first $(, $rest)* });
+
+        impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> $point<T> {
+            /// Returns the distance between the points when only moving along the axes.
+            pub fn manhattan_distance(self, other: Self) -> T {
+                abs_diff(self.$first, other.$first) $(+ abs_diff(self.$rest, other.$rest))*
+            }
+
+            /// Returns the distance between the points when diagonal moves are allowed too.
+            pub fn chebyshev_distance(self, other: Self) -> T {
+                abs_diff(self.$first, other.$first) $(.max(abs_diff(self.$rest, other.$rest)))*
+            }
+        }
+    };
+    (@ops $point:ident { $($component:ident),+ }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($component: self.$component + other.$component),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($component: self.$component - other.$component),+ }
+            }
+        }
+
+        impl<T: Copy + Add<Output = T>> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Copy + Sub<Output = T>> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($component: -self.$component),+ }
+            }
+        }
+
+        /// Scales every component by the same amount.
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, scale: T) -> Self {
+                Self { $($component: self.$component * scale),+ }
+            }
+        }
+
+        impl<T: Copy + Ord> $point<T> {
+            /// Returns the smaller of each component of the two points.
+            pub fn component_min(self, other: Self) -> Self {
+                Self { $($component: self.$component.min(other.$component)),+ }
+            }
+
+            /// Returns the larger of each component of the two points.
+            pub fn component_max(self, other: Self) -> Self {
+                Self { $($component: self.$component.max(other.$component)),+ }
+            }
+        }
+
+        impl<T: Copy + Ord + From<i8>> $point<T> {
+            /// Returns the point with each component replaced by -1, 0 or 1 depending on its sign,
+            /// which is a single step (diagonal or otherwise) in its direction.
+            pub fn signum(self) -> Self {
+                Self { $($component: signum(self.$component)),+ }
+            }
+        }
+    };
+}
+
+impl_point!(Point2 { x, y });
+impl_point!(Point3 { x, y, z });
+
+impl<T: Copy + Add<Output = T> + From<i8>> Point2<T> {
+    /// Returns the 4 points sharing an edge with this one.
+    pub fn neighbors4(self) -> [Self; 4] {
+        Direction::ALL.map(|direction| self + direction.step())
+    }
+
+    /// Returns the 8 points sharing an edge or a corner with this one.
+    pub fn neighbors8(self) -> [Self; 8] {
+        Direction8::ALL.map(|direction| self + direction.step())
+    }
+}
+
+impl<T: Copy + Add<Output = T> + From<i8>> Point3<T> {
+    /// Returns the 6 points sharing a face with this one.
+    pub fn neighbors6(self) -> [Self; 6] {
+        [
+            (-1, 0, 0),
+            (1, 0, 0),
+            (0, -1, 0),
+            (0, 1, 0),
+            (0, 0, -1),
+            (0, 0, 1),
+        ]
+        .map(|(x, y, z)| self + Point3::new(T::from(x), T::from(y), T::from(z)))
+    }
+}
+
+/// Parses `s` as comma-separated coordinates, such as `498,4` or `2,2,6`.
+fn parse_components<T: FromStr, const N: usize>(
+    s: &str,
+    expected: &'static str,
+) -> Result<[T; N], ParseError> {
+    let components: Vec<&str> = s.split(',').collect();
+
+    if components.len() != N {
+        return Err(ParseError::new(ParseErrorKind::Unexpected(expected), s, s));
+    }
+
+    let mut parsed = Vec::with_capacity(N);
+
+    for component in components {
+        parsed.push(parse_number(s, component.trim())?);
+    }
+
+    // The number of components was checked above.
+    Ok(parsed.try_into().ok().unwrap())
+}
+
+/// Parses a point written as `x,y`.
+impl<T: FromStr> FromStr for Point2<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_components(s, "exactly 2 coordinates")?;
+
+        Ok(Self { x, y })
+    }
+}
+
+/// Parses a point written as `x,y,z`.
+impl<T: FromStr> FromStr for Point3<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_components(s, "exactly 3 coordinates")?;
+
+        Ok(Self { x, y, z })
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(Point2 { x, y }: Point2<T>) -> Self {
+        (x, y)
+    }
+}
+
+/// One of the 4 orthogonal directions, in clockwise order starting from up.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Returns the offset of a single step in this direction.
+    pub fn step<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Self::Up => (0, -1),
+            Self::Right => (1, 0),
+            Self::Down => (0, 1),
+            Self::Left => (-1, 0),
+        };
+
+        Point2::new(T::from(x), T::from(y))
+    }
+}
+
+/// One of the 4 orthogonal or 4 diagonal directions, in clockwise order starting from up.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Rotates the direction clockwise by `eighths` of a full turn.
+    pub fn rotate_clockwise(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// Rotates the direction anticlockwise by `eighths` of a full turn.
+    pub fn rotate_anticlockwise(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + 8 - eighths % 8) % 8]
+    }
+
+    /// Returns the offset of a single step in this direction.
+    pub fn step<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Self::Up => (0, -1),
+            Self::UpRight => (1, -1),
+            Self::Right => (1, 0),
+            Self::DownRight => (1, 1),
+            Self::Down => (0, 1),
+            Self::DownLeft => (-1, 1),
+            Self::Left => (-1, 0),
+            Self::UpLeft => (-1, -1),
+        };
+
+        Point2::new(T::from(x), T::from(y))
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+/// An axis-aligned bounding box around 2D points, including its edges.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Aabb2<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+/// An axis-aligned bounding box around 3D points, including its faces.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Aabb3<T> {
+    pub min: Point3<T>,
+    pub max: Point3<T>,
+}
+
+/// Implements the methods shared by both kinds of bounding box.
+macro_rules! impl_aabb {
+    ($aabb:ident, $point:ident { $($component:ident),+ }) => {
+        impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> $aabb<T> {
+            /// Creates the box with the given corners, which may be given in any order.
+            pub fn new(corner: $point<T>, other_corner: $point<T>) -> Self {
+                Self {
+                    min: corner.component_min(other_corner),
+                    max: corner.component_max(other_corner),
+                }
+            }
+
+            /// Returns the smallest box containing every point, or `None` if there are none.
+            pub fn from_points(points: impl IntoIterator<Item = $point<T>>) -> Option<Self> {
+                let mut points = points.into_iter();
+
+                let first = points.next()?;
+
+                Some(points.fold(Self::new(first, first), Self::including))
+            }
+
+            /// Returns the smallest box containing both this box and `point`.
+            pub fn including(self, point: $point<T>) -> Self {
+                Self {
+                    min: self.min.component_min(point),
+                    max: self.max.component_max(point),
+                }
+            }
+
+            /// Returns the box grown by `margin` in every direction.
+            pub fn expanded(self, margin: T) -> Self {
+                let margin = $point { $($component: margin),+ };
+
+                Self {
+                    min: self.min - margin,
+                    max: self.max + margin,
+                }
+            }
+
+            pub fn contains(&self, point: $point<T>) -> bool {
+                $(self.min.$component <= point.$component && point.$component <= self.max.$component)&&+
+            }
+        }
+    };
+}
+
+impl_aabb!(Aabb2, Point2 { x, y });
+impl_aabb!(Aabb3, Point3 { x, y, z });
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<i8>> Aabb2<T> {
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::from(1)
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::from(1)
+    }
+}
+
+impl Aabb2<i32> {
+    /// Returns every point in the box, row by row from the top.
+    pub fn positions(&self) -> impl Iterator<Item = Point2<i32>> {
+        let Self { min, max } = *self;
+
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point2::new(x, y)))
+    }
+}
+
+impl Aabb3<i32> {
+    /// Returns every point in the box, one layer of `z` at a time.
+    pub fn positions(&self) -> impl Iterator<Item = Point3<i32>> {
+        let Self { min, max } = *self;
+
+        (min.z..=max.z).flat_map(move |z| {
+            (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point3::new(x, y, z)))
+        })
+    }
+}
@@ -0,0 +1,321 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{
+    geometry::{Direction, Direction8},
+    parse::{ParseError, ParseErrorKind},
+};
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// Positions are `(x, y)` pairs, with `x` counting columns from the left and `y` counting rows
+/// from the top.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, row by row, or returns `None` if they don't fill a whole
+    /// number of rows of the given width.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 {
+            return cells.is_empty().then_some(Self {
+                width,
+                height: 0,
+                cells,
+            });
+        }
+
+        cells.len().is_multiple_of(width).then(|| Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Parses a map with one character per cell and one line per row, converting each character
+    /// with `parse_cell`. A character it returns `None` for is reported as not being `expected`.
+    pub fn parse(
+        s: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+        expected: &'static str,
+    ) -> Result<Self, ParseError> {
+        let width = s
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::missing("a grid", s))?
+            .chars()
+            .count();
+
+        let mut cells = Vec::with_capacity(s.len());
+
+        for line in s.lines() {
+            if line.chars().count() != width {
+                return Err(ParseError::new(
+                    ParseErrorKind::Unexpected("every row to be as wide as the first"),
+                    s,
+                    line,
+                ));
+            }
+
+            for (index, char) in line.char_indices() {
+                let cell = parse_cell(char).ok_or_else(|| {
+                    ParseError::new(
+                        ParseErrorKind::Unexpected(expected),
+                        s,
+                        &line[index..index + char.len_utf8()],
+                    )
+                })?;
+
+                cells.push(cell);
+            }
+        }
+
+        Ok(Self {
+            width,
+            height: cells.len() / width.max(1),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.flat_index(position)])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.flat_index(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    fn flat_index(&self, (x, y): (usize, usize)) -> usize {
+        y * self.width + x
+    }
+
+    fn position_of(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    /// Returns the position one `step` away from `position`, if it's within the grid.
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+        self.contains(position).then_some(position)
+    }
+
+    /// Returns the positions of the (up to) 4 cells sharing an edge with `position`, in the
+    /// order of [`Direction::ALL`].
+    pub fn neighbors4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction.step::<isize>().into()))
+    }
+
+    /// Returns the positions of the (up to) 8 cells sharing an edge or a corner with `position`,
+    /// in the order of [`Direction8::ALL`].
+    pub fn neighbors8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction.step::<isize>().into()))
+    }
+
+    /// Returns the positions reached by repeatedly taking `step` from `position`, nearest first
+    /// and not including `position` itself, until the edge of the grid.
+    pub fn ray(
+        &self,
+        position: (usize, usize),
+        step: (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        std::iter::successors(self.step(position, step), move |&position| {
+            self.step(position, step)
+        })
+    }
+
+    /// Returns every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.position_of(index), cell))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.cells.len()).map(|index| self.position_of(index))
+    }
+
+    /// Returns the position of the first cell, row by row, which matches `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.position_of(index))
+    }
+
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            y < self.height,
+            "Row {y} is outside of a {}x{} grid.",
+            self.width,
+            self.height
+        );
+
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "Column {x} is outside of a {}x{} grid.",
+            self.width,
+            self.height
+        );
+
+        self.cells[x..].iter().step_by(self.width).take(self.height)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Encodes the grid as a binary PPM image with one pixel per cell, coloured by the red, green
+    /// and blue values `colour` gives it.
+    pub fn to_ppm(&self, mut colour: impl FnMut(&T) -> [u8; 3]) -> Vec<u8> {
+        let mut image = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+
+        for cell in &self.cells {
+            image.extend(colour(cell));
+        }
+
+        image
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Returns the grid reflected along its main diagonal, so that rows become columns.
+    pub fn transpose(&self) -> Self {
+        self.rebuild(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// Returns the grid rotated a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self {
+        self.rebuild(self.height, self.width, |(x, y)| (y, self.height - 1 - x))
+    }
+
+    /// Returns the grid rotated a quarter turn anticlockwise.
+    pub fn rotate_anticlockwise(&self) -> Self {
+        self.rebuild(self.height, self.width, |(x, y)| (self.width - 1 - y, x))
+    }
+
+    /// Builds a grid of the given size, taking each cell from the position in this grid which
+    /// `source` maps its position to.
+    fn rebuild(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn((usize, usize)) -> (usize, usize),
+    ) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|position| self[source(position)].clone())
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &Self::Output {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "Position {position:?} is outside of a {}x{} grid.",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!("Position {position:?} is outside of a {width}x{height} grid.")
+        })
+    }
+}
+
+/// Parses a character map as-is, accepting any character.
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, Some, "any character")
+    }
+}
+
+/// Draws the grid with one line per row, with no separators between cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
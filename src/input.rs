@@ -0,0 +1,122 @@
+use std::{
+    env,
+    error::Error,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{AnyResult, CustomError};
+
+/// Names a directory holding puzzle inputs as `day<N>.txt`, which is checked before the cache.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// One of the places a puzzle input is looked for, in order of precedence.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InputSource {
+    /// A path given explicitly, e.g. with `--input`.
+    Explicit,
+    /// The directory named by [`INPUT_DIR_VAR`].
+    EnvDir,
+    /// The per-user cache directory.
+    Cache,
+    /// The input checked in alongside the day's solution.
+    Bundled,
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Explicit => write!(f, "explicit path"),
+            Self::EnvDir => write!(f, "${INPUT_DIR_VAR}"),
+            Self::Cache => write!(f, "user cache"),
+            Self::Bundled => write!(f, "bundled input"),
+        }
+    }
+}
+
+/// Returned when none of the places a day's input could be found in have it.
+#[derive(Debug)]
+pub struct InputNotFound {
+    pub day: u8,
+    /// Every location which was checked, in the order they were checked.
+    pub tried: Vec<(InputSource, PathBuf)>,
+}
+
+impl Error for InputNotFound {}
+
+impl fmt::Display for InputNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No input found for day {}. Tried:", self.day)?;
+
+        for (source, path) in &self.tried {
+            write!(f, "\n  {source}: {}", path.display())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the path of the puzzle input which is checked in alongside the given day's solution.
+pub fn bundled_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/days")
+        .join(format!("day{day}"))
+        .join("input.txt")
+}
+
+/// Returns the directory inputs are cached in for the current user, if it can be determined.
+pub fn cache_dir() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("LOCALAPPDATA").map(PathBuf::from))
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))?;
+
+    Some(base.join("adventofcode_2022"))
+}
+
+/// Lists every location the given day's input could be read from, in order of precedence.
+///
+/// An explicit path overrides everything else, so that a typo in it is reported rather than
+/// silently falling back to another input.
+pub fn candidates(day: u8, explicit: Option<&Path>) -> Vec<(InputSource, PathBuf)> {
+    if let Some(path) = explicit {
+        return vec![(InputSource::Explicit, path.to_path_buf())];
+    }
+
+    let file_name = format!("day{day}.txt");
+
+    let mut candidates = Vec::new();
+
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+        candidates.push((InputSource::EnvDir, Path::new(&dir).join(&file_name)));
+    }
+
+    if let Some(dir) = cache_dir() {
+        candidates.push((InputSource::Cache, dir.join(&file_name)));
+    }
+
+    candidates.push((InputSource::Bundled, bundled_path(day)));
+
+    candidates
+}
+
+/// Returns the first location the given day's input exists at.
+pub fn resolve(day: u8, explicit: Option<&Path>) -> Result<PathBuf, InputNotFound> {
+    let tried = candidates(day, explicit);
+
+    match tried.iter().find(|(_, path)| path.is_file()) {
+        Some((_, path)) => Ok(path.clone()),
+        None => Err(InputNotFound { day, tried }),
+    }
+}
+
+/// Resolves the given day's input, then reads it.
+pub fn read(day: u8, explicit: Option<&Path>) -> AnyResult<String> {
+    let path = resolve(day, explicit)?;
+
+    let input = fs::read_to_string(&path).map_err(|err| CustomError {
+        msg: format!("Failed to read {}: {err}", path.display()).into(),
+    })?;
+
+    Ok(input)
+}
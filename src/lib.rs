@@ -1,6 +1,187 @@
-use std::{borrow::Cow, error::Error, fmt};
+pub mod bench;
+pub mod cycle;
+pub mod days;
+pub mod geometry;
+pub mod grid;
+pub mod hash;
+pub mod input;
+pub mod parse;
+pub mod search;
+pub mod snafu;
 
-pub type AnyResult = Result<(), Box<dyn Error>>;
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use serde::Serialize;
+
+use bench::DayTimings;
+use parse::ParseError;
+
+pub use days::DAYS;
+
+pub type AnyResult<T = ()> = Result<T, Box<dyn Error>>;
+
+/// A single day's puzzle, split into a parsing step and the two parts which are solved from its
+/// output.
+pub trait Solution {
+    type Parsed<'a>;
+    type Answer: Into<Answer>;
+
+    /// Whether the puzzle has a second part. If it doesn't, `part2` is never called, and the
+    /// second part is skipped wherever days are run.
+    const HAS_PART2: bool = true;
+
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>>;
+
+    fn part1(parsed: &Self::Parsed<'_>) -> AnyResult<Self::Answer>;
+
+    fn part2(parsed: &Self::Parsed<'_>) -> AnyResult<Self::Answer>;
+}
+
+/// The answer to one part of a day's puzzle.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// The pixels drawn on a CRT screen, with each row separated by a newline.
+    Crt(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(integer) => write!(f, "{integer}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Crt(pixels) => write!(f, "\n{pixels}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Self::Integer(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_integer!(i32, i64, u32, u64);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Self::Integer(value as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+/// One of the two halves of a day's puzzle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A single day of the calendar, with its `Solution` erased so that every day can be stored
+/// side by side.
+pub struct Day {
+    pub number: u8,
+    has_part2: bool,
+    solve: fn(u8, &str, Part) -> AnyResult<Answer>,
+    bench: fn(u8, &str, usize) -> AnyResult<DayTimings>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(number: u8) -> Self {
+        Self {
+            number,
+            has_part2: S::HAS_PART2,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+
+    /// Returns the parts of this day's puzzle, which is only the first for a day without a
+    /// second part.
+    pub fn parts(&self) -> &'static [Part] {
+        if self.has_part2 {
+            &[Part::One, Part::Two]
+        } else {
+            &[Part::One]
+        }
+    }
+
+    /// Returns the path of the puzzle input which is checked in alongside this day's solution.
+    pub fn bundled_input_path(&self) -> PathBuf {
+        input::bundled_path(self.number)
+    }
+
+    /// Reads this day's puzzle input from `explicit` if given, otherwise from the first location
+    /// it's found in, as described in [`input::candidates`].
+    pub fn read_input(&self, explicit: Option<&Path>) -> AnyResult<String> {
+        input::read(self.number, explicit)
+    }
+
+    /// Parses `input`, then returns the answer to the given part.
+    pub fn solve(&self, input: &str, part: Part) -> AnyResult<Answer> {
+        (self.solve)(self.number, input, part)
+    }
+
+    /// Times parsing `input` and solving each part separately, `iterations` times over.
+    pub fn bench(&self, input: &str, iterations: usize) -> AnyResult<DayTimings> {
+        (self.bench)(self.number, input, iterations)
+    }
+}
+
+fn solve<S: Solution>(day: u8, input: &str, part: Part) -> AnyResult<Answer> {
+    if part == Part::Two && !S::HAS_PART2 {
+        return Err(Box::new(CustomError {
+            msg: format!("Day {day} has no second part.").into(),
+        }));
+    }
+
+    let parsed = parse_day::<S>(day, input)?;
+
+    match part {
+        Part::One => S::part1(&parsed).map(Into::into),
+        Part::Two => S::part2(&parsed).map(Into::into),
+    }
+}
+
+/// Parses `input` with `S`, filling in the day of any `ParseError` which doesn't know it yet.
+pub(crate) fn parse_day<S: Solution>(day: u8, input: &str) -> AnyResult<S::Parsed<'_>> {
+    S::parse(input).map_err(|err| match err.downcast::<ParseError>() {
+        Ok(parse_error) => Box::new(parse_error.with_day(day)) as Box<dyn Error>,
+        Err(err) => err,
+    })
+}
+
+/// Returns the day with the given number, or `None` if it hasn't been solved yet.
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
 
 #[derive(Debug)]
 pub struct CustomError {
@@ -1,20 +1,20 @@
-use std::fs;
+use crate::{AnyResult, Solution};
 
-use adventofcode_2022::AnyResult;
+pub struct DayX;
 
-fn main() -> AnyResult {
-    let input = fs::read_to_string("src/bin/dayX/input.txt")?;
+impl Solution for DayX {
+    type Parsed<'a> = &'a str;
+    type Answer = u32;
 
-    part1(&input)?;
-    part2(&input)?;
+    fn parse(input: &str) -> AnyResult<Self::Parsed<'_>> {
+        Ok(input)
+    }
 
-    Ok(())
-}
-
-fn part1(input: &str) -> AnyResult {
-    Ok(())
-}
+    fn part1(input: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        Ok(0)
+    }
 
-fn part2(input: &str) -> AnyResult {
-    Ok(())
+    fn part2(input: &Self::Parsed<'_>) -> AnyResult<Self::Answer> {
+        Ok(0)
+    }
 }
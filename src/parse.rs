@@ -0,0 +1,178 @@
+use std::{error::Error, fmt, ops::Range};
+
+/// What was wrong with the text a [`ParseError`] points at.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseErrorKind {
+    /// A separator between two values, e.g. the `,` in `2-4,6-8`, wasn't found.
+    MissingDelimiter(&'static str),
+    /// Something which should have been a number couldn't be parsed as one.
+    InvalidNumber,
+    /// The text doesn't match what was expected, which is described by the contained message.
+    Unexpected(&'static str),
+    /// Something which must appear somewhere in the input never did.
+    Missing(&'static str),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingDelimiter(delimiter) => write!(f, "missing delimiter `{delimiter}`"),
+            Self::InvalidNumber => write!(f, "invalid number"),
+            Self::Unexpected(expected) => write!(f, "expected {expected}"),
+            Self::Missing(missing) => write!(f, "missing {missing}"),
+        }
+    }
+}
+
+/// An error in a puzzle input, located down to the line and columns it occurred on.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    /// The day whose input failed to parse, once known. Parsers shared between days leave this
+    /// to be filled in by [`Day`](crate::Day).
+    pub day: Option<u8>,
+    /// The 1-based line the error occurred on.
+    pub line: usize,
+    /// The 0-based range of columns, counted in characters, of the offending text.
+    pub columns: Range<usize>,
+    /// The offending text itself.
+    pub text: String,
+    /// The whole line the error occurred on.
+    pub source_line: String,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    /// Creates an error pointing at `offending`, which must be a slice of `source`, the text
+    /// being parsed. Lines are counted from the start of `source`; see [`Self::rebased`] for
+    /// when `source` is itself only part of the input.
+    pub fn new(kind: ParseErrorKind, source: &str, offending: &str) -> Self {
+        let start = offset_in(source, offending);
+        let end = start + offending.len();
+
+        let line_start = source[..start].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |index| start + index);
+        let source_line = &source[line_start..line_end];
+
+        let first_column = source[line_start..start].chars().count();
+        let last_column = first_column + source[start..end.min(line_end)].chars().count();
+
+        Self {
+            day: None,
+            line: source[..start].matches('\n').count() + 1,
+            columns: first_column..last_column,
+            text: offending.to_owned(),
+            source_line: source_line.trim_end_matches('\r').to_owned(),
+            kind,
+        }
+    }
+
+    /// Creates an error for something missing from `source`, pointing just past its end.
+    pub fn missing(what: &'static str, source: &str) -> Self {
+        Self::new(
+            ParseErrorKind::Missing(what),
+            source,
+            &source[source.len()..],
+        )
+    }
+
+    /// Moves an error created while parsing `parsed` so that it points at the same text within
+    /// `source`, which `parsed` must be a slice of. This lets parsers of a single line or value
+    /// report errors relative to it, leaving callers to place them within the whole input.
+    pub fn rebased(self, source: &str, parsed: &str) -> Self {
+        let line_start: usize = parsed
+            .split_inclusive('\n')
+            .take(self.line - 1)
+            .map(str::len)
+            .sum();
+
+        let byte_offset = |column| {
+            self.source_line
+                .char_indices()
+                .nth(column)
+                .map_or(self.source_line.len(), |(index, _)| index)
+        };
+
+        let line_start = offset_in(source, parsed) + line_start;
+        let start = line_start + byte_offset(self.columns.start);
+        let end = line_start + byte_offset(self.columns.end);
+
+        Self {
+            day: self.day,
+            ..Self::new(self.kind, source, &source[start..end])
+        }
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Error for ParseError {}
+
+/// Renders the error in the style of rustc, underlining the offending text in its line.
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+
+        writeln!(f, "{}", self.kind)?;
+
+        match self.day {
+            Some(day) => write!(f, "{gutter}--> day {day}, ")?,
+            None => write!(f, "{gutter}--> ")?,
+        }
+
+        writeln!(f, "line {}:{}", self.line, self.columns.start + 1)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line_number} | {}", self.source_line)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.columns.start),
+            "^".repeat(self.columns.len().max(1))
+        )
+    }
+}
+
+/// Returns the byte offset of `part` within `whole`, which it must be a slice of.
+fn offset_in(whole: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(whole.as_ptr() as usize);
+
+    assert!(
+        offset + part.len() <= whole.len(),
+        "The offending text of a parse error must be a slice of the text being parsed."
+    );
+
+    offset
+}
+
+/// Parses every line of `input` with `parse`, moving each error to the line it came from.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    parse: impl Fn(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse(line).map_err(|err| err.rebased(input, line)))
+        .collect()
+}
+
+/// Parses `text`, which must be a slice of `source`, as a number, reporting where it is if it
+/// isn't one.
+pub fn parse_number<T: std::str::FromStr>(source: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(ParseErrorKind::InvalidNumber, source, text))
+}
+
+/// Splits `text`, which must be a slice of `source`, at the first `delimiter`.
+pub fn split_once<'a>(
+    source: &str,
+    text: &'a str,
+    delimiter: &'static str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(ParseErrorKind::MissingDelimiter(delimiter), source, text))
+}
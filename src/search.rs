@@ -0,0 +1,264 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::hash::FastHashMap;
+
+/// Something which can list the nodes one step away from a node, such as a map whose cells
+/// can be moved between.
+///
+/// Any `Fn(&N) -> impl IntoIterator<Item = N>` closure implements this, so a one-off graph can
+/// be searched without declaring a type for it.
+pub trait Neighbors<N> {
+    fn neighbors(&self, node: &N) -> impl IntoIterator<Item = N>;
+}
+
+impl<N, F, I> Neighbors<N> for F
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    fn neighbors(&self, node: &N) -> impl IntoIterator<Item = N> {
+        self(node)
+    }
+}
+
+/// Like [`Neighbors`], but where each step has a cost of its own.
+///
+/// Any `Fn(&N) -> impl IntoIterator<Item = (N, C)>` closure implements this.
+pub trait WeightedNeighbors<N, C> {
+    fn weighted_neighbors(&self, node: &N) -> impl IntoIterator<Item = (N, C)>;
+}
+
+impl<N, C, F, I> WeightedNeighbors<N, C> for F
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    fn weighted_neighbors(&self, node: &N) -> impl IntoIterator<Item = (N, C)> {
+        self(node)
+    }
+}
+
+/// Everything a search found: the cost of reaching each node it settled, the route taken to
+/// each of them, and the goal it stopped at, if any.
+#[derive(Clone, Debug)]
+pub struct SearchResult<N, C> {
+    costs: FastHashMap<N, C>,
+    came_from: FastHashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> SearchResult<N, C> {
+    fn new() -> Self {
+        Self {
+            costs: FastHashMap::default(),
+            came_from: FastHashMap::default(),
+            goal: None,
+        }
+    }
+
+    /// Returns the goal the search stopped at, or `None` if it ran out of nodes to visit first.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// Returns the cost of the cheapest route to the goal the search stopped at.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    /// Returns the cost of the cheapest route from any start to `node`, if it was reached.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// Returns every node which was reached, along with the cost of the cheapest route to it.
+    ///
+    /// When the search stopped early at a goal, nodes which are as cheap to reach as the goal
+    /// may be missing.
+    pub fn costs(&self) -> &FastHashMap<N, C> {
+        &self.costs
+    }
+
+    /// Returns the cheapest route to `node`, from the start it was reached from up to and
+    /// including `node` itself.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(node) {
+            return None;
+        }
+
+        let mut path: Vec<N> =
+            std::iter::successors(Some(node.clone()), |node| self.came_from.get(node).cloned())
+                .collect();
+
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Returns the cheapest route to the goal the search stopped at.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+}
+
+/// Searches outward from every one of `starts` at once, one step at a time, stopping as soon as
+/// a node matching `is_goal` is reached. Pass `|_| false` to visit everything reachable.
+///
+/// Costs are the number of steps taken.
+pub fn bfs<N: Clone + Eq + Hash>(
+    graph: &impl Neighbors<N>,
+    starts: impl IntoIterator<Item = N>,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, usize> {
+    let mut result = SearchResult::new();
+
+    let mut frontier = VecDeque::new();
+
+    for start in starts {
+        if !result.costs.contains_key(&start) {
+            result.costs.insert(start.clone(), 0);
+            frontier.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, cost)) = frontier.pop_front() {
+        if is_goal(&node) {
+            result.goal = Some(node);
+            break;
+        }
+
+        for neighbor in graph.neighbors(&node) {
+            if !result.costs.contains_key(&neighbor) {
+                result.costs.insert(neighbor.clone(), cost + 1);
+                result.came_from.insert(neighbor.clone(), node.clone());
+                frontier.push_back((neighbor, cost + 1));
+            }
+        }
+    }
+
+    result
+}
+
+/// Searches outward from every one of `starts` at once, cheapest route first, stopping as soon
+/// as a node matching `is_goal` is reached. Pass `|_| false` to visit everything reachable.
+///
+/// Costs start from `C::default()` and must never be negative.
+pub fn dijkstra<N, C>(
+    graph: &impl WeightedNeighbors<N, C>,
+    starts: impl IntoIterator<Item = N>,
+    is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+{
+    astar(graph, starts, is_goal, |_| C::default())
+}
+
+/// Like [`dijkstra`], but visits nodes which `heuristic` estimates to be closer to a goal
+/// first.
+///
+/// The heuristic must never overestimate the cost of reaching a goal, and must not drop by
+/// more than the cost of a step when taking it, otherwise the routes found may not be the
+/// cheapest.
+pub fn astar<N, C>(
+    graph: &impl WeightedNeighbors<N, C>,
+    starts: impl IntoIterator<Item = N>,
+    mut is_goal: impl FnMut(&N) -> bool,
+    mut heuristic: impl FnMut(&N) -> C,
+) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+{
+    let mut result = SearchResult::new();
+
+    let mut frontier = BinaryHeap::new();
+
+    for start in starts {
+        frontier.push(Queued {
+            priority: heuristic(&start),
+            cost: C::default(),
+            node: start,
+            came_from: None,
+        });
+    }
+
+    while let Some(Queued {
+        cost,
+        node,
+        came_from,
+        ..
+    }) = frontier.pop()
+    {
+        // Nodes are queued again whenever a route to them is found, rather than updated in
+        // place, so every copy after the cheapest is skipped.
+        if result.costs.contains_key(&node) {
+            continue;
+        }
+
+        result.costs.insert(node.clone(), cost);
+
+        if let Some(previous) = came_from {
+            result.came_from.insert(node.clone(), previous);
+        }
+
+        if is_goal(&node) {
+            result.goal = Some(node);
+            break;
+        }
+
+        for (neighbor, step_cost) in graph.weighted_neighbors(&node) {
+            if !result.costs.contains_key(&neighbor) {
+                let cost = cost + step_cost;
+
+                frontier.push(Queued {
+                    priority: cost + heuristic(&neighbor),
+                    cost,
+                    node: neighbor,
+                    came_from: Some(node.clone()),
+                });
+            }
+        }
+    }
+
+    result
+}
+
+/// A node waiting to be visited, ordered so that a [`BinaryHeap`] pops the lowest priority first.
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+    came_from: Option<N>,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Between equal priorities, prefer whichever has come further, as it is likely to be
+        // nearer the goal.
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
@@ -0,0 +1,204 @@
+use std::{fmt, iter::Sum, ops::Add, str::FromStr};
+
+use crate::{
+    parse::{ParseError, ParseErrorKind},
+    CustomError,
+};
+
+/// A number written in SNAFU, the Elves' balanced base 5, where each digit is worth -2 to 2
+/// times its place value and `-` and `=` stand for -1 and -2.
+///
+/// Numbers are stored as their digits rather than converted to a primitive integer, so that
+/// adding them can never overflow, however many are summed.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Snafu {
+    /// The digits, least significant first, without any leading zeroes. Zero has no digits.
+    digits: Vec<i8>,
+}
+
+impl Snafu {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    fn trimmed(mut self) -> Self {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+
+        self
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::missing("a SNAFU number", s));
+        }
+
+        let digits = s
+            .char_indices()
+            .rev()
+            .map(|(index, char)| match char {
+                '2' => Ok(2),
+                '1' => Ok(1),
+                '0' => Ok(0),
+                '-' => Ok(-1),
+                '=' => Ok(-2),
+                _ => Err(ParseError::new(
+                    ParseErrorKind::Unexpected("a SNAFU digit, one of `2`, `1`, `0`, `-` or `=`"),
+                    s,
+                    &s[index..index + char.len_utf8()],
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { digits }.trimmed())
+    }
+}
+
+impl fmt::Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.digits.is_empty() {
+            return f.pad("0");
+        }
+
+        let snafu: String = self
+            .digits
+            .iter()
+            .rev()
+            .map(|digit| match digit {
+                2 => '2',
+                1 => '1',
+                0 => '0',
+                -1 => '-',
+                _ => '=',
+            })
+            .collect();
+
+        f.pad(&snafu)
+    }
+}
+
+/// Implements conversions between SNAFU numbers and a signed primitive integer.
+macro_rules! impl_snafu_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Snafu {
+                fn from(mut value: $integer) -> Self {
+                    let mut digits = Vec::new();
+
+                    while value != 0 {
+                        // Dividing first, then carrying into the quotient, stays in range even
+                        // for the most negative value.
+                        let mut digit = value.rem_euclid(5);
+                        value = value.div_euclid(5);
+
+                        if digit > 2 {
+                            digit -= 5;
+                            value += 1;
+                        }
+
+                        digits.push(digit as i8);
+                    }
+
+                    Self { digits }
+                }
+            }
+
+            impl TryFrom<&Snafu> for $integer {
+                type Error = CustomError;
+
+                fn try_from(snafu: &Snafu) -> Result<Self, Self::Error> {
+                    snafu
+                        .digits
+                        .iter()
+                        .rev()
+                        .try_fold(0 as $integer, |value, &digit| {
+                            // Multiplying by five before adding the digit can overflow on the
+                            // way to the most negative value, so the digit is added to one of
+                            // the five copies of `value` instead.
+                            value
+                                .checked_mul(4)?
+                                .checked_add(value.checked_add(digit.into())?)
+                        })
+                        .ok_or_else(|| CustomError {
+                            msg: format!(
+                                "The SNAFU number {snafu} doesn't fit in {}.",
+                                stringify!($integer)
+                            )
+                            .into(),
+                        })
+                }
+            }
+
+            impl TryFrom<Snafu> for $integer {
+                type Error = CustomError;
+
+                fn try_from(snafu: Snafu) -> Result<Self, Self::Error> {
+                    Self::try_from(&snafu)
+                }
+            }
+        )*
+    };
+}
+
+impl_snafu_integer!(i64, i128);
+
+/// Adds digit by digit, carrying into the next place whenever a digit leaves the range -2 to 2.
+impl Add<&Snafu> for &Snafu {
+    type Output = Snafu;
+
+    fn add(self, other: &Snafu) -> Snafu {
+        let num_digits = self.digits.len().max(other.digits.len());
+
+        let mut digits = Vec::with_capacity(num_digits + 1);
+        let mut carry = 0;
+
+        for index in 0..num_digits {
+            let mut digit = self.digits.get(index).copied().unwrap_or_default()
+                + other.digits.get(index).copied().unwrap_or_default()
+                + carry;
+
+            carry = 0;
+
+            if digit > 2 {
+                digit -= 5;
+                carry = 1;
+            } else if digit < -2 {
+                digit += 5;
+                carry = -1;
+            }
+
+            digits.push(digit);
+        }
+
+        if carry != 0 {
+            digits.push(carry);
+        }
+
+        Snafu { digits }.trimmed()
+    }
+}
+
+impl Add for Snafu {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        &self + &other
+    }
+}
+
+impl<'a> Sum<&'a Snafu> for Snafu {
+    fn sum<I: Iterator<Item = &'a Snafu>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, snafu| &total + snafu)
+    }
+}
+
+impl Sum for Snafu {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, snafu| &total + &snafu)
+    }
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use std::{fs, path::Path};
+
+use adventofcode_2022::{find_day, Answer, Part};
+use common::example_path;
+use pretty_assertions::assert_str_eq;
+use toml::{Table, Value};
+
+/// Renders an answer the same way as an expected answer in `answers.toml`, so that a mismatch
+/// can be shown as a line-by-line diff.
+fn render_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Crt(pixels) => pixels.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn render_expected(expected: &Value) -> String {
+    match expected {
+        Value::Integer(integer) => integer.to_string(),
+        Value::String(string) => string.clone(),
+        other => panic!("Expected answers should be integers or strings, found {other}."),
+    }
+}
+
+/// Solves the given part of a day for every case in `answers.toml` which has an expected answer
+/// for it, panicking with a diff on the first mismatch.
+fn check_answers(day_number: u8, part: Part) {
+    let tests_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+
+    let answers: Table = fs::read_to_string(tests_dir.join("answers.toml"))
+        .expect("Failed to read answers.toml.")
+        .parse()
+        .expect("answers.toml is not valid TOML.");
+
+    let day =
+        find_day(day_number).unwrap_or_else(|| panic!("Day {day_number} has not been solved yet."));
+
+    let cases = answers
+        .get(&format!("day{day_number}"))
+        .and_then(Value::as_table)
+        .unwrap_or_else(|| panic!("answers.toml has no table for day {day_number}."));
+
+    let part_key = format!("part{part}");
+    let mut num_checked_cases = 0;
+
+    for (case, expected_answers) in cases {
+        let Some(expected) = expected_answers.get(&part_key) else {
+            continue;
+        };
+
+        let input_path = if case == "input" {
+            day.bundled_input_path()
+        } else {
+            example_path(day_number, case)
+        };
+
+        let input = fs::read_to_string(&input_path)
+            .unwrap_or_else(|err| panic!("Failed to read {}: {err}", input_path.display()));
+
+        let actual = day
+            .solve(&input, part)
+            .unwrap_or_else(|err| panic!("Day {day_number} part {part} failed on `{case}`: {err}"));
+
+        assert_str_eq!(
+            render_expected(expected),
+            render_answer(&actual),
+            "Day {day_number} part {part} gave the wrong answer for `{case}`."
+        );
+
+        num_checked_cases += 1;
+    }
+
+    assert!(
+        num_checked_cases > 0,
+        "answers.toml has no cases for day {day_number} part {part}."
+    );
+}
+
+macro_rules! answer_tests {
+    ($($(#[$attribute:meta])* $name:ident => ($day:literal, $part:ident),)*) => {
+        $(
+            #[test]
+            $(#[$attribute])*
+            fn $name() {
+                check_answers($day, Part::$part);
+            }
+        )*
+    };
+}
+
+answer_tests! {
+    day1_part1 => (1, One),
+    day1_part2 => (1, Two),
+    day2_part1 => (2, One),
+    day2_part2 => (2, Two),
+    day3_part1 => (3, One),
+    day3_part2 => (3, Two),
+    day4_part1 => (4, One),
+    day4_part2 => (4, Two),
+    day5_part1 => (5, One),
+    day5_part2 => (5, Two),
+    day6_part1 => (6, One),
+    day6_part2 => (6, Two),
+    day7_part1 => (7, One),
+    day7_part2 => (7, Two),
+    day8_part1 => (8, One),
+    day8_part2 => (8, Two),
+    day9_part1 => (9, One),
+    day9_part2 => (9, Two),
+    day10_part1 => (10, One),
+    day10_part2 => (10, Two),
+    day11_part1 => (11, One),
+    day11_part2 => (11, Two),
+    day12_part1 => (12, One),
+    day12_part2 => (12, Two),
+    day13_part1 => (13, One),
+    day13_part2 => (13, Two),
+    day14_part1 => (14, One),
+    day14_part2 => (14, Two),
+    day15_part1 => (15, One),
+    day15_part2 => (15, Two),
+    day16_part1 => (16, One),
+    day16_part2 => (16, Two),
+    day17_part1 => (17, One),
+    day17_part2 => (17, Two),
+    day18_part1 => (18, One),
+    day18_part2 => (18, Two),
+    day19_part1 => (19, One),
+    day19_part2 => (19, Two),
+    day20_part1 => (20, One),
+    day20_part2 => (20, Two),
+    day21_part1 => (21, One),
+    day21_part2 => (21, Two),
+    day22_part1 => (22, One),
+    day22_part2 => (22, Two),
+    day23_part1 => (23, One),
+    day23_part2 => (23, Two),
+    day24_part1 => (24, One),
+    day24_part2 => (24, Two),
+    day25_part1 => (25, One),
+}
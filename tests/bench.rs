@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use adventofcode_2022::bench::{find_regressions, DayTimings, Stage, Stats};
+
+fn stats(median_ns: u64) -> Stats {
+    Stats {
+        median_ns,
+        min_ns: median_ns / 2,
+        max_ns: median_ns * 2,
+    }
+}
+
+fn timings(parse_ns: u64, part1_ns: u64, part2_ns: u64) -> DayTimings {
+    DayTimings {
+        day: 1,
+        parse: stats(parse_ns),
+        part1: stats(part1_ns),
+        part2: Some(stats(part2_ns)),
+    }
+}
+
+#[test]
+fn stats_summarise_odd_and_even_sample_counts() {
+    let mut odd = [30, 10, 20].map(Duration::from_nanos);
+    let mut even = [40, 10, 30, 20].map(Duration::from_nanos);
+
+    assert_eq!(
+        Stats::from_samples(&mut odd),
+        Stats {
+            median_ns: 20,
+            min_ns: 10,
+            max_ns: 30
+        }
+    );
+    assert_eq!(
+        Stats::from_samples(&mut even),
+        Stats {
+            median_ns: 25,
+            min_ns: 10,
+            max_ns: 40
+        }
+    );
+}
+
+#[test]
+fn only_stages_slower_than_the_threshold_are_regressions() {
+    let previous = timings(100, 100, 100);
+    let current = timings(105, 150, 80);
+
+    let regressions = find_regressions(&previous, &current, 0.1);
+
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].stage, Stage::Part1);
+    assert!((regressions[0].slowdown() - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn stages_with_a_zero_previous_median_are_never_regressions() {
+    let previous = timings(0, 0, 100);
+    let current = timings(0, 50, 100);
+
+    assert!(find_regressions(&previous, &current, 0.1).is_empty());
+}
+
+#[test]
+fn missing_stages_are_never_regressions() {
+    let previous = timings(100, 100, 100);
+    let current = DayTimings {
+        part2: None,
+        ..timings(100, 100, 100)
+    };
+
+    assert!(find_regressions(&previous, &current, 0.1).is_empty());
+    assert!(find_regressions(&current, &previous, 0.1).is_empty());
+}
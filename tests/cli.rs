@@ -0,0 +1,55 @@
+use std::{
+    env, fs,
+    process::{Command, Output},
+};
+
+use adventofcode_2022::input::{self, INPUT_DIR_VAR};
+
+/// Runs every day with an empty user cache, and with `$AOC_INPUT_DIR` pointing at a directory
+/// holding `inputs`, so that only the bundled inputs and those are found.
+fn run_all(name: &str, inputs: &[(&str, &str)]) -> Output {
+    let dir = env::temp_dir().join(format!("aoc_cli_test_{name}_{}", std::process::id()));
+    let input_dir = dir.join("inputs");
+    let cache_dir = dir.join("cache");
+    fs::create_dir_all(&input_dir).unwrap();
+    fs::create_dir_all(&cache_dir).unwrap();
+
+    for (file_name, contents) in inputs {
+        fs::write(input_dir.join(file_name), contents).unwrap();
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "all"])
+        .env(INPUT_DIR_VAR, &input_dir)
+        .env("XDG_CACHE_HOME", &cache_dir)
+        .output()
+        .unwrap();
+
+    fs::remove_dir_all(&dir).unwrap();
+
+    output
+}
+
+#[test]
+fn days_without_an_input_are_skipped() {
+    assert!(!input::bundled_path(25).is_file());
+
+    let output = run_all("skipped", &[]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("Day 25 skipped (no input)"));
+}
+
+#[test]
+fn days_which_fail_to_parse_still_fail() {
+    let output = run_all("failed", &[("day25.txt", "not a snafu number\n")]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("part(s) failed to produce an answer."));
+}
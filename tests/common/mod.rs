@@ -0,0 +1,24 @@
+// Every test crate compiles its own copy of this module, and most only use part of it.
+#![allow(dead_code)]
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Returns the path of one of the examples from the given day's puzzle, which are kept in
+/// `tests/examples` and named after the case `answers.toml` checks them as.
+pub fn example_path(day: u8, case: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/examples")
+        .join(format!("day{day}"))
+        .join(format!("{case}.txt"))
+}
+
+/// Reads the main example from the given day's puzzle.
+pub fn read_example(day: u8) -> String {
+    let path = example_path(day, "example");
+
+    fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("Failed to read {}: {err}", path.display()))
+}
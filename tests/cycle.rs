@@ -0,0 +1,46 @@
+use adventofcode_2022::cycle::find_cycle;
+
+/// A deterministic stream which wanders for a while before falling into a loop.
+fn wander(seed: u64) -> impl Iterator<Item = u64> {
+    std::iter::successors(Some(seed), |&value| Some((value * value + 1) % 1009))
+}
+
+#[test]
+fn finds_where_the_states_repeat() {
+    let cycle = find_cycle(wander(3), |&value| value, |&value| value).unwrap();
+
+    let values: Vec<u64> = wander(3).take(cycle.start() + cycle.length() + 1).collect();
+
+    assert!(cycle.length() > 0);
+    assert_eq!(
+        values[cycle.start()],
+        values[cycle.start() + cycle.length()]
+    );
+    assert!(!values[..cycle.start() + cycle.length()]
+        .iter()
+        .enumerate()
+        .any(|(index, value)| values[index + 1..cycle.start() + cycle.length()].contains(value)));
+}
+
+#[test]
+fn extrapolates_a_running_total() {
+    // The running total of the stream, which grows by the same amount every cycle.
+    let totals = || {
+        wander(3).scan(0, |total, value| {
+            let before = *total;
+            *total += value;
+            Some((value, before))
+        })
+    };
+
+    let cycle = find_cycle(totals(), |&(value, _)| value, |&(_, total)| total).unwrap();
+
+    for (steps, (_, total)) in totals().enumerate().take(5_000) {
+        assert_eq!(cycle.extrapolate(steps as u64), Some(total));
+    }
+}
+
+#[test]
+fn gives_up_when_the_states_run_out() {
+    assert!(find_cycle(0..100, |&value| value, |&value| value).is_none());
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use adventofcode_2022::{
+    days::day12::{Heightmap, Traversal},
+    grid::Grid,
+};
+use common::read_example;
+
+/// Follows the arrows from `start`, checking that each step climbs at most one level, and
+/// returns how many steps it took to reach `E`.
+fn follow_arrows(arrows: &Grid<char>, map: &Grid<char>, start: (usize, usize)) -> usize {
+    let elevation = |position: (usize, usize)| match map[position] {
+        'S' => 'a',
+        'E' => 'z',
+        other => other,
+    } as i32;
+
+    let mut position = start;
+    let mut steps = 0;
+
+    while arrows[position] != 'E' {
+        let (x, y) = position;
+
+        let next = match arrows[position] {
+            '>' => (x + 1, y),
+            '<' => (x - 1, y),
+            'v' => (x, y + 1),
+            '^' => (x, y - 1),
+            other => panic!("The route stopped at `{other}` before reaching the end."),
+        };
+
+        assert!(elevation(next) <= elevation(position) + 1);
+
+        position = next;
+        steps += 1;
+    }
+
+    steps
+}
+
+#[test]
+fn draws_the_route_in_either_direction() {
+    let input = read_example(12);
+    let map: Grid<char> = input.parse().unwrap();
+    let heightmap: Heightmap = input.parse().unwrap();
+
+    for (traversal, start, steps) in [
+        (Traversal::Forward, (0, 0), 31),
+        (Traversal::Reverse, (0, 4), 29),
+    ] {
+        let route = heightmap.route(traversal).unwrap();
+        let arrows = route.arrows();
+
+        assert_eq!((route.start(), route.steps()), (start, steps));
+        assert_eq!(follow_arrows(&arrows, &map, start), steps);
+        assert_eq!(
+            arrows.iter().filter(|(_, &arrow)| arrow != '.').count(),
+            steps + 1
+        );
+    }
+}
+
+#[test]
+fn highlights_the_start_of_the_route() {
+    let heightmap: Heightmap = read_example(12).parse().unwrap();
+    let route = heightmap.route(Traversal::Reverse).unwrap();
+
+    let ansi = route.to_ansi();
+    let fifth_row = ansi.lines().nth(4).unwrap();
+
+    assert_eq!(ansi.lines().count(), 5);
+    assert!(fifth_row.starts_with("\x1b[48;2;220;40;40m>"));
+
+    let ppm = route.to_ppm();
+    let header = b"P6\n8 5\n255\n";
+    let start_pixel = header.len() + 3 * (4 * 8);
+
+    assert_eq!(&ppm[..header.len()], header);
+    assert_eq!(ppm.len(), header.len() + 3 * 8 * 5);
+    assert_eq!(ppm[start_pixel..start_pixel + 3], [220, 40, 40]);
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use adventofcode_2022::days::day14::{parse_cave, Cave};
+use common::read_example;
+use pretty_assertions::assert_eq;
+
+fn example_cave() -> Cave {
+    parse_cave(&read_example(14)).unwrap()
+}
+
+#[test]
+fn draws_the_cave_before_and_after_pouring_sand() {
+    let mut cave = example_cave();
+
+    assert_eq!(
+        cave.picture().to_string(),
+        "\
+......+...
+..........
+..........
+..........
+....#...##
+....#...#.
+..###...#.
+........#.
+........#.
+#########."
+    );
+
+    assert_eq!(cave.pour_sand(), 24);
+    assert_eq!(
+        cave.picture().to_string(),
+        "\
+......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########."
+    );
+}
+
+#[test]
+fn draws_the_floor_under_the_sand() {
+    let mut cave = example_cave().with_floor();
+
+    assert_eq!(cave.pour_sand(), 93);
+    assert_eq!(
+        cave.picture().to_string(),
+        "\
+..........o..........
+.........ooo.........
+........ooooo........
+.......ooooooo.......
+......oo#ooo##o......
+.....ooo#ooo#ooo.....
+....oo###ooo#oooo....
+...oooo.oooo#ooooo...
+..oooooooooo#oooooo..
+.ooo#########ooooooo.
+ooooo.......ooooooooo
+#####################"
+    );
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use adventofcode_2022::{
+    days::day15::{tuning_frequency, BeaconExclusionZone},
+    geometry::Point2,
+};
+use common::read_example;
+
+fn example_zone() -> BeaconExclusionZone {
+    read_example(15).parse().unwrap()
+}
+
+#[test]
+fn solves_the_example_with_its_own_row_and_bound() {
+    let zone = example_zone();
+
+    assert_eq!(zone.coverage(10), vec![-2..=24]);
+    assert_eq!(zone.num_without_beacon(10), 26);
+
+    let beacon = zone.distress_beacon(20).unwrap();
+
+    assert_eq!(beacon, Point2::new(14, 11));
+    assert_eq!(tuning_frequency(beacon), 56_000_011);
+}
+
+#[test]
+fn merges_coverage_which_only_touches() {
+    let zone: BeaconExclusionZone = "\
+Sensor at x=0, y=0: closest beacon is at x=2, y=0
+Sensor at x=5, y=0: closest beacon is at x=5, y=2
+Sensor at x=20, y=0: closest beacon is at x=21, y=0"
+        .parse()
+        .unwrap();
+
+    assert_eq!(zone.coverage(0), vec![-2..=7, 19..=21]);
+    assert_eq!(zone.num_without_beacon(0), 11);
+}
+
+#[test]
+fn finds_a_distress_beacon_on_the_edge_of_the_search_area() {
+    let in_corner: BeaconExclusionZone = "Sensor at x=3, y=3: closest beacon is at x=3, y=8"
+        .parse()
+        .unwrap();
+
+    assert_eq!(in_corner.distress_beacon(4), Some(Point2::new(0, 0)));
+
+    let on_edge: BeaconExclusionZone = "\
+Sensor at x=2, y=4: closest beacon is at x=2, y=8
+Sensor at x=6, y=3: closest beacon is at x=6, y=7
+Sensor at x=2, y=0: closest beacon is at x=2, y=2"
+        .parse()
+        .unwrap();
+
+    assert_eq!(on_edge.distress_beacon(6), Some(Point2::new(0, 1)));
+}
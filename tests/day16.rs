@@ -0,0 +1,21 @@
+use adventofcode_2022::{find_day, Part};
+
+#[test]
+fn unreachable_valves_are_reported() {
+    let input = "\
+Valve AA has flow rate=0; tunnel leads to valve BB
+Valve BB has flow rate=13; tunnel leads to valve AA
+Valve CC has flow rate=2; tunnel leads to valve DD
+Valve DD has flow rate=0; tunnel leads to valve CC
+";
+
+    let err = find_day(16)
+        .unwrap()
+        .solve(input, Part::One)
+        .expect_err("Valve CC can't be reached.");
+
+    assert_eq!(
+        err.to_string(),
+        "CustomError(Valve CC can't be reached from valve BB.)"
+    );
+}
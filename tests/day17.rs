@@ -0,0 +1,142 @@
+mod common;
+
+use adventofcode_2022::days::day17::{parse_jets, Jet, Simulation};
+use common::read_example;
+use pretty_assertions::assert_eq;
+
+fn example_jets() -> Vec<Jet> {
+    parse_jets(&read_example(17)).unwrap()
+}
+
+#[test]
+fn draws_the_tower_after_ten_rocks() {
+    let jets = example_jets();
+    let mut simulation = Simulation::new(&jets);
+
+    simulation.run_with_limit(10);
+
+    assert_eq!(
+        simulation.chamber(None, None).to_string(),
+        "\
+|....#..|
+|....#..|
+|....##.|
+|##..##.|
+|######.|
+|.###...|
+|..#....|
+|.####..|
+|....##.|
+|....##.|
+|....#..|
+|..#.#..|
+|..#.#..|
+|#####..|
+|..###..|
+|...#...|
+|..####.|
++-------+
+"
+    );
+
+    assert_eq!(
+        simulation.chamber(None, Some(2)).to_string(),
+        "|....#..|\n|....#..|\n"
+    );
+}
+
+#[test]
+fn replays_each_step_of_the_first_rock() {
+    let jets = example_jets();
+    let mut simulation = Simulation::new(&jets);
+    let mut replay = String::new();
+
+    simulation.drop_rock_with(|simulation, rock, event| {
+        replay += &format!("{event}\n{}\n", simulation.chamber(Some(rock), None));
+    });
+
+    assert_eq!(
+        replay,
+        "\
+The first rock begins falling:
+|..@@@@.|
+|.......|
+|.......|
+|.......|
++-------+
+
+Jet of gas pushes rock right:
+|...@@@@|
+|.......|
+|.......|
+|.......|
++-------+
+
+Rock falls 1 unit:
+|...@@@@|
+|.......|
+|.......|
++-------+
+
+Jet of gas pushes rock right, but nothing happens:
+|...@@@@|
+|.......|
+|.......|
++-------+
+
+Rock falls 1 unit:
+|...@@@@|
+|.......|
++-------+
+
+Jet of gas pushes rock right, but nothing happens:
+|...@@@@|
+|.......|
++-------+
+
+Rock falls 1 unit:
+|...@@@@|
++-------+
+
+Jet of gas pushes rock left:
+|..@@@@.|
++-------+
+
+Rock falls 1 unit, causing it to come to rest:
+|..####.|
++-------+
+
+"
+    );
+}
+
+#[test]
+fn keeps_only_the_top_of_a_tall_tower() {
+    let jets = example_jets();
+    let mut simulation = Simulation::new(&jets);
+
+    simulation.run_with_limit(200_000);
+
+    let height = Simulation::new(&jets).height_after(200_000).unwrap();
+    let chamber = simulation.chamber(None, None);
+
+    assert_eq!(simulation.highest_y, height);
+    assert!(chamber.cells.height() < 1_000);
+    assert!(!chamber.has_floor);
+}
+
+#[test]
+fn rocks_already_dropped_count_towards_the_height() {
+    let jets = example_jets();
+    let mut simulation = Simulation::new(&jets);
+
+    simulation.run_with_limit(500);
+
+    assert_eq!(simulation.height_after(2022).unwrap(), 3068);
+
+    let mut simulation = Simulation::new(&jets);
+
+    simulation.run_with_limit(500);
+
+    assert!(simulation.height_after(499).is_err());
+}
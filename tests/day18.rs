@@ -0,0 +1,92 @@
+mod common;
+
+use std::collections::HashMap;
+
+use adventofcode_2022::{
+    days::day18::{parse_droplet, Droplet, Pocket},
+    geometry::{Aabb3, Point3},
+};
+use common::read_example;
+
+fn example_droplet() -> Droplet {
+    parse_droplet(&read_example(18)).unwrap()
+}
+
+/// A 3x3x3 cube of lava with the cube in its centre missing.
+fn hollow_cube() -> Droplet {
+    let input: Vec<String> = Aabb3::new(Point3::new(0, 0, 0), Point3::new(2, 2, 2))
+        .positions()
+        .filter(|&position| position != Point3::new(1, 1, 1))
+        .map(|position| format!("{},{},{}", position.x, position.y, position.z))
+        .collect();
+
+    parse_droplet(&input.join("\n")).unwrap()
+}
+
+#[test]
+fn reports_the_pockets_trapped_inside() {
+    let pocket_at = |x, y, z| Pocket {
+        size: 1,
+        bounds: Aabb3::new(Point3::new(x, y, z), Point3::new(x, y, z)),
+    };
+
+    assert_eq!(example_droplet().pockets(), vec![pocket_at(2, 2, 5)]);
+
+    let hollow_cube = hollow_cube();
+
+    assert_eq!(hollow_cube.pockets(), vec![pocket_at(1, 1, 1)]);
+    assert_eq!(hollow_cube.surface_area(), 60);
+    assert_eq!(hollow_cube.exterior_surface_area(), 54);
+}
+
+#[test]
+fn exports_a_closed_mesh_of_the_exterior() {
+    for droplet in [example_droplet(), hollow_cube()] {
+        let mesh = droplet.exterior_mesh();
+
+        assert_eq!(mesh.faces.len(), droplet.exterior_surface_area());
+
+        // Every edge of a closed mesh whose faces all wind the same way is crossed once in each
+        // direction.
+        let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+
+        for face in &mesh.faces {
+            for corner in 0..4 {
+                *edges
+                    .entry((face[corner], face[(corner + 1) % 4]))
+                    .or_default() += 1;
+            }
+        }
+
+        for (&(from, to), &count) in &edges {
+            assert_eq!(count, 1);
+            assert_eq!(edges.get(&(to, from)), Some(&1));
+        }
+    }
+}
+
+#[test]
+fn points_the_faces_of_the_mesh_outwards() {
+    let mesh = parse_droplet("4,-2,7").unwrap().exterior_mesh();
+
+    // The volume enclosed by the faces, which is only positive if they're wound anticlockwise
+    // when seen from outside.
+    let volume: i32 = mesh
+        .faces
+        .iter()
+        .map(|face| {
+            let [a, b, c, d] = face.map(|index| mesh.vertices[index]);
+
+            [(a, b, c), (a, c, d)]
+                .into_iter()
+                .map(|(a, b, c)| {
+                    a.x * (b.y * c.z - b.z * c.y) - a.y * (b.x * c.z - b.z * c.x)
+                        + a.z * (b.x * c.y - b.y * c.x)
+                })
+                .sum::<i32>()
+        })
+        .sum();
+
+    assert_eq!((mesh.faces.len(), mesh.vertices.len()), (6, 8));
+    assert_eq!(volume, 6);
+}
@@ -0,0 +1,37 @@
+mod common;
+
+use adventofcode_2022::days::day19::{parse_blueprints, Resource};
+use common::read_example;
+
+#[test]
+fn explains_a_plan_which_opens_the_most_geodes() {
+    let blueprints = parse_blueprints(&read_example(19)).unwrap();
+
+    let plan = blueprints[0].plan(24);
+
+    assert_eq!((plan.blueprint_id, plan.geodes), (1, 9));
+
+    // Each geode robot opens one geode for every minute after the one it was built in.
+    let geodes_opened: u32 = plan
+        .builds
+        .iter()
+        .filter(|build| build.robot == Resource::Geode)
+        .map(|build| plan.minutes - build.minute)
+        .sum();
+
+    assert_eq!(geodes_opened, plan.geodes);
+    assert!(plan
+        .builds
+        .windows(2)
+        .all(|pair| pair[0].minute < pair[1].minute));
+}
+
+#[test]
+fn sentences_must_end_with_full_stops() {
+    let blueprint = "Blueprint 1: Each ore robot costs 4 ore! Each clay robot costs 2 ore. Each \
+                     obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 \
+                     obsidian.";
+
+    assert!(parse_blueprints(blueprint).is_err());
+    assert!(parse_blueprints(&blueprint.replace('!', ".")).is_ok());
+}
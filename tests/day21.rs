@@ -0,0 +1,10 @@
+use adventofcode_2022::{find_day, Part};
+
+#[test]
+fn refuses_to_solve_for_humn_on_both_sides() {
+    let input = "root: abcd + efgh\nabcd: humn * twoo\nefgh: humn + twoo\ntwoo: 2\nhumn: 5\n";
+
+    let err = find_day(21).unwrap().solve(input, Part::Two).unwrap_err();
+
+    assert!(err.to_string().contains("`humn` appears on both sides"));
+}
@@ -0,0 +1,83 @@
+use adventofcode_2022::{find_day, Answer, Part};
+
+/// Every net of a cube, up to rotation and reflection, with `#` marking the faces.
+const NETS: [&str; 11] = [
+    "#...\n####\n#...",
+    "#...\n####\n.#..",
+    "#...\n####\n..#.",
+    "#...\n####\n...#",
+    ".#..\n####\n.#..",
+    ".#..\n####\n..#.",
+    "##..\n.###\n.#..",
+    "##..\n.###\n..#.",
+    "##..\n.###\n...#",
+    "##..\n.##.\n..##",
+    "###..\n..###",
+];
+
+const FACE_SIZE: usize = 3;
+
+/// Draws a board with no walls in the shape of `net`.
+fn board(net: &str) -> String {
+    net.lines()
+        .flat_map(|row| {
+            let line: String = row
+                .chars()
+                .flat_map(|face| [if face == '#' { '.' } else { ' ' }; FACE_SIZE])
+                .collect();
+
+            vec![line.trim_end().to_owned(); FACE_SIZE]
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn password(net: &str, path: &str) -> Answer {
+    let input = format!("{}\n\n{path}\n", board(net));
+
+    find_day(22)
+        .unwrap()
+        .solve(&input, Part::Two)
+        .unwrap_or_else(|err| panic!("Failed to walk around\n{net}\n{err}"))
+}
+
+#[test]
+fn walking_around_the_cube_returns_to_the_start() {
+    for net in NETS {
+        let start = password(net, "0");
+
+        assert_eq!(password(net, &(4 * FACE_SIZE).to_string()), start, "{net}");
+    }
+}
+
+#[test]
+fn retracing_a_path_returns_to_the_start() {
+    let path = "5R7L11R2R13L4L9R3R17L6";
+
+    // Turning around, then undoing each instruction in reverse order, turning the other way
+    // each time, and turning around again should exactly retrace the path.
+    let mut instructions: Vec<String> = Vec::new();
+
+    for char in path.chars() {
+        match (char, instructions.last_mut()) {
+            ('0'..='9', Some(steps)) if steps.ends_with(|last: char| last.is_ascii_digit()) => {
+                steps.push(char);
+            }
+            ('L', _) => instructions.push("R".to_owned()),
+            ('R', _) => instructions.push("L".to_owned()),
+            _ => instructions.push(char.to_string()),
+        }
+    }
+
+    let retraced: String = instructions.into_iter().rev().collect();
+
+    for net in NETS {
+        let start = password(net, "0");
+
+        assert_eq!(
+            password(net, &format!("{path}RR{retraced}RR0")),
+            start,
+            "{net}"
+        );
+    }
+}
@@ -0,0 +1,14 @@
+mod common;
+
+use adventofcode_2022::{find_day, Part};
+use common::read_example;
+
+#[test]
+fn only_the_first_part_is_solved_and_timed() {
+    let day = find_day(25).unwrap();
+    let input = read_example(25);
+
+    assert_eq!(day.parts(), [Part::One]);
+    assert!(day.solve(&input, Part::Two).is_err());
+    assert!(day.bench(&input, 1).unwrap().part2.is_none());
+}
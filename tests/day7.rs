@@ -0,0 +1,234 @@
+mod common;
+
+use adventofcode_2022::{
+    days::day7::{human_size, parse_file_system, Problem, ProblemKind},
+    find_day,
+    parse::{ParseError, ParseErrorKind},
+    Answer, Part,
+};
+use common::read_example;
+use pretty_assertions::assert_eq;
+
+/// Lists `/` twice, then changes into a directory which neither listing showed.
+const PROBLEMATIC_INPUT: &str = "\
+$ cd /
+$ ls
+dir a
+10 x
+$ cd a
+$ ls
+20 y
+$ cd ..
+$ ls
+dir a
+10 x
+$ cd b
+$ ls
+5 z
+";
+
+#[test]
+fn draws_the_example_as_a_tree_with_sizes() {
+    let input = read_example(7);
+    let file_system = parse_file_system(&input).unwrap();
+
+    assert_eq!(
+        file_system.to_string(),
+        "\
+/ (48381165)
+├── a/ (94853)
+│   ├── e/ (584)
+│   │   └── i (584)
+│   ├── f (29116)
+│   ├── g (2557)
+│   └── h.lst (62596)
+├── b.txt (14848514)
+├── c.dat (8504156)
+└── d/ (24933642)
+    ├── j (4060174)
+    ├── d.log (8033020)
+    ├── d.ext (5626152)
+    └── k (7214296)
+"
+    );
+}
+
+#[test]
+fn resolves_paths() {
+    let input = read_example(7);
+    let file_system = parse_file_system(&input).unwrap();
+
+    let i = file_system.resolve("/a/e/i").unwrap();
+
+    assert_eq!(file_system.entry(i).size, 584);
+    assert_eq!(file_system.path(i), "/a/e/i");
+
+    assert_eq!(file_system.resolve("/"), Some(file_system.root()));
+    assert_eq!(
+        file_system.resolve("/a/e/../../d/./k"),
+        file_system.resolve("/d/k")
+    );
+    assert_eq!(file_system.resolve("/.."), Some(file_system.root()));
+    assert_eq!(file_system.resolve("/a/x"), None);
+    assert_eq!(file_system.resolve("/b.txt/c"), None);
+}
+
+#[test]
+fn finds_entries_matching_globs() {
+    let input = read_example(7);
+    let file_system = parse_file_system(&input).unwrap();
+
+    let paths = |pattern| -> Vec<String> {
+        file_system
+            .glob(pattern)
+            .into_iter()
+            .map(|id| file_system.path(id))
+            .collect()
+    };
+
+    assert_eq!(paths("/*.???"), ["/b.txt", "/c.dat"]);
+    assert_eq!(paths("/a/*/i"), ["/a/e/i"]);
+    assert_eq!(paths("/d/d.*"), ["/d/d.log", "/d/d.ext"]);
+    assert_eq!(
+        paths("**/?"),
+        ["/a", "/a/e", "/a/e/i", "/a/f", "/a/g", "/d", "/d/j", "/d/k"]
+    );
+    assert_eq!(paths("/**/e/**"), ["/a/e", "/a/e/i"]);
+    assert_eq!(paths("/*z*"), Vec::<String>::new());
+}
+
+#[test]
+fn reports_disk_usage_biggest_first() {
+    let input = read_example(7);
+    let file_system = parse_file_system(&input).unwrap();
+
+    assert_eq!(
+        file_system.disk_usage(None),
+        [
+            ("/".into(), 48381165),
+            ("/d".into(), 24933642),
+            ("/a".into(), 94853),
+            ("/a/e".into(), 584),
+        ]
+    );
+
+    assert_eq!(file_system.disk_usage(Some(0)), [("/".into(), 48381165)]);
+    assert_eq!(file_system.disk_usage(Some(1)).len(), 3);
+}
+
+#[test]
+fn formats_sizes_like_du() {
+    assert_eq!(human_size(0), "0");
+    assert_eq!(human_size(1023), "1023");
+    assert_eq!(human_size(1024), "1.0K");
+    assert_eq!(human_size(1025), "1.1K");
+    assert_eq!(human_size(94853), "93K");
+    assert_eq!(human_size(10 * 1024 * 1024 - 1), "10M");
+    assert_eq!(human_size(48381165), "47M");
+    assert_eq!(human_size(1024 * 1024 * 1024 - 1), "1.0G");
+}
+
+#[test]
+fn reports_repeated_listings_and_unknown_directories_with_line_numbers() {
+    let file_system = parse_file_system(PROBLEMATIC_INPUT).unwrap();
+
+    assert_eq!(
+        file_system.problems(),
+        [
+            Problem {
+                line: 9,
+                text: "$ ls",
+                kind: ProblemKind::RepeatedListing {
+                    path: "/".into(),
+                    first_line: 2,
+                },
+            },
+            Problem {
+                line: 12,
+                text: "b",
+                kind: ProblemKind::UnknownDirectory {
+                    parent_path: "/".into(),
+                },
+            },
+        ]
+    );
+
+    let messages: Vec<String> = file_system
+        .problems()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    assert_eq!(
+        messages,
+        [
+            "line 9: `ls` lists / again, after already listing it on line 2",
+            "line 12: `cd` into `b`, which no `ls` of / had shown",
+        ]
+    );
+
+    // The repeated listing isn't counted twice, and the unknown directory is assumed to exist.
+    assert_eq!(file_system.entry(file_system.root()).size, 35);
+    assert_eq!(
+        file_system
+            .resolve("/b/z")
+            .map(|id| file_system.entry(id).size),
+        Some(5)
+    );
+}
+
+#[test]
+fn solving_fails_at_the_first_problem() {
+    let err = find_day(7)
+        .unwrap()
+        .solve(PROBLEMATIC_INPUT, Part::One)
+        .expect_err("The input lists a directory twice.");
+
+    let err = err.downcast::<ParseError>().unwrap();
+
+    assert_eq!(err.line, 9);
+    assert_eq!(err.text, "$ ls");
+    assert_eq!(
+        err.kind,
+        ParseErrorKind::Unexpected("a directory which hasn't been listed already")
+    );
+}
+
+#[test]
+fn an_empty_input_is_missing_the_first_command() {
+    let buffer = "$ cd /\n$ ls\n";
+
+    for input in ["", &buffer[5..5]] {
+        let err = parse_file_system(input)
+            .err()
+            .expect("An empty input has no commands.");
+
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Missing("the first command `$ cd /`")
+        );
+    }
+}
+
+#[test]
+fn nothing_is_deleted_when_there_is_already_enough_space() {
+    let answer = find_day(7)
+        .unwrap()
+        .solve("$ cd /\n$ ls\n10 x\n", Part::Two)
+        .unwrap();
+
+    assert_eq!(answer, Answer::Integer(0));
+}
+
+#[test]
+fn files_bigger_than_the_disk_are_reported() {
+    let err = find_day(7)
+        .unwrap()
+        .solve("$ cd /\n$ ls\n70000001 x\n", Part::Two)
+        .expect_err("The file doesn't fit on the disk.");
+
+    assert_eq!(
+        err.to_string(),
+        "CustomError(The files take up 70000001, more than the disk's 70000000.)"
+    );
+}
@@ -0,0 +1,67 @@
+use adventofcode_2022::geometry::{Aabb2, Aabb3, Direction, Direction8, Point2, Point3};
+
+#[test]
+fn points_support_arithmetic() {
+    let mut point = Point2::new(3, -2);
+
+    point += Point2::new(1, 1);
+    assert_eq!(point, Point2::new(4, -1));
+    assert_eq!(point - Point2::new(4, 4), Point2::new(0, -5));
+    assert_eq!(-point * 2, Point2::new(-8, 2));
+    assert_eq!(Point2::new(-7, 0).signum(), Point2::new(-1, 0));
+}
+
+#[test]
+fn measures_distances() {
+    let (a, b) = (Point2::new(1u32, 8), Point2::new(4, 2));
+
+    assert_eq!(a.manhattan_distance(b), 9);
+    assert_eq!(a.chebyshev_distance(b), 6);
+    assert_eq!(
+        Point3::new(0, 0, 0).manhattan_distance(Point3::new(-1, 2, -3)),
+        6
+    );
+}
+
+#[test]
+fn parses_comma_separated_coordinates() {
+    assert_eq!("498,4".parse(), Ok(Point2::new(498, 4)));
+    assert_eq!("2,2,-6".parse(), Ok(Point3::new(2, 2, -6)));
+    assert!("1,2".parse::<Point3<i32>>().is_err());
+}
+
+#[test]
+fn directions_rotate() {
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert_eq!(Direction::Left.opposite(), Direction::Right);
+    assert_eq!(Direction::Down.step::<i32>(), Point2::new(0, 1));
+
+    assert_eq!(Direction8::Up.rotate_clockwise(3), Direction8::DownRight);
+    assert_eq!(Direction8::Up.rotate_anticlockwise(1), Direction8::UpLeft);
+    assert_eq!(Direction8::from(Direction::Left), Direction8::Left);
+}
+
+#[test]
+fn bounding_boxes_contain_their_points() {
+    let points = [Point2::new(2, 5), Point2::new(-1, 3), Point2::new(4, 4)];
+
+    let aabb = Aabb2::from_points(points).unwrap();
+
+    assert_eq!(aabb, Aabb2::new(Point2::new(4, 5), Point2::new(-1, 3)));
+    assert_eq!((aabb.width(), aabb.height()), (6, 3));
+    assert!(points.into_iter().all(|point| aabb.contains(point)));
+    assert!(!aabb.contains(Point2::new(5, 4)));
+    assert!(aabb.expanded(1).contains(Point2::new(5, 4)));
+
+    let cube = Aabb3::new(Point3::new(0, 0, 0), Point3::new(1, 1, 1));
+    assert!(Point3::new(0, 0, 0)
+        .neighbors6()
+        .into_iter()
+        .filter(|&neighbor| cube.contains(neighbor))
+        .eq([
+            Point3::new(1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, 0, 1)
+        ]));
+}
@@ -0,0 +1,88 @@
+use adventofcode_2022::{
+    geometry::{Direction, Direction8, Point2},
+    grid::Grid,
+};
+use pretty_assertions::assert_str_eq;
+
+fn example() -> Grid<char> {
+    "abc\ndef".parse().unwrap()
+}
+
+#[test]
+fn parses_and_displays_character_maps() {
+    let grid = example();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 1)], 'f');
+    assert_eq!(grid.get((3, 0)), None);
+    assert_str_eq!(grid.to_string(), "abc\ndef");
+}
+
+#[test]
+fn rejects_ragged_rows() {
+    let err = "abc\nde\n".parse::<Grid<char>>().unwrap_err();
+
+    assert_eq!(err.line, 2);
+}
+
+#[test]
+fn finds_neighbors_within_bounds() {
+    let grid = example();
+
+    let mut corner: Vec<_> = grid.neighbors4((0, 0)).collect();
+    corner.sort();
+    assert_eq!(corner, [(0, 1), (1, 0)]);
+
+    assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+}
+
+#[test]
+fn neighbors_follow_the_order_of_the_directions() {
+    let grid = Grid::from_cells(3, (0..9).collect()).unwrap();
+
+    let around_centre = |steps: &[Point2<isize>]| -> Vec<(usize, usize)> {
+        steps
+            .iter()
+            .map(|step| ((1 + step.x) as usize, (1 + step.y) as usize))
+            .collect()
+    };
+
+    assert_eq!(
+        grid.neighbors4((1, 1)).collect::<Vec<_>>(),
+        around_centre(&Direction::ALL.map(Direction::step))
+    );
+    assert_eq!(
+        grid.neighbors8((1, 1)).collect::<Vec<_>>(),
+        around_centre(&Direction8::ALL.map(Direction8::step))
+    );
+}
+
+#[test]
+fn iterates_rows_columns_and_rays() {
+    let grid = example();
+
+    assert_eq!(grid.row(1).collect::<String>(), "def");
+    assert_eq!(grid.column(2).collect::<String>(), "cf");
+    assert_eq!(
+        grid.ray((0, 0), (1, 0))
+            .map(|position| grid[position])
+            .collect::<String>(),
+        "bc"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Column 3 is outside of a 3x2 grid.")]
+fn columns_past_the_edge_panic() {
+    example().column(3).for_each(drop);
+}
+
+#[test]
+fn transposes_and_rotates() {
+    let grid = example();
+
+    assert_str_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+    assert_str_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+    assert_str_eq!(grid.rotate_anticlockwise().to_string(), "cf\nbe\nad");
+    assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+}
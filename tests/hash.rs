@@ -0,0 +1,34 @@
+use std::hash::{BuildHasher, BuildHasherDefault};
+
+use adventofcode_2022::{
+    geometry::Point2,
+    hash::{FastHashSet, FastHasher},
+};
+
+#[test]
+fn neighboring_points_hash_differently() {
+    let hasher = BuildHasherDefault::<FastHasher>::default();
+
+    let origin = Point2::new(0, 0);
+
+    for neighbor in origin.neighbors8() {
+        assert_ne!(hasher.hash_one(origin), hasher.hash_one(neighbor));
+    }
+
+    // Swapping the coordinates shouldn't collide either.
+    assert_ne!(
+        hasher.hash_one(Point2::new(1, 2)),
+        hasher.hash_one(Point2::new(2, 1))
+    );
+}
+
+#[test]
+fn sets_hold_points() {
+    let points: FastHashSet<Point2<i32>> = (-50..50)
+        .flat_map(|x| (-50..50).map(move |y| Point2::new(x, y)))
+        .collect();
+
+    assert_eq!(points.len(), 10_000);
+    assert!(points.contains(&Point2::new(-50, 49)));
+    assert!(!points.contains(&Point2::new(50, 0)));
+}
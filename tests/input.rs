@@ -0,0 +1,40 @@
+use std::{env, fs, path::Path};
+
+use adventofcode_2022::input::{self, InputSource, INPUT_DIR_VAR};
+
+#[test]
+fn explicit_path_is_not_silently_replaced() {
+    let missing = Path::new("definitely/not/an/input.txt");
+
+    let err = input::resolve(1, Some(missing)).unwrap_err();
+
+    assert_eq!(
+        err.tried,
+        vec![(InputSource::Explicit, missing.to_path_buf())]
+    );
+    assert!(err.to_string().contains("definitely/not/an/input.txt"));
+}
+
+// The only test which touches `AOC_INPUT_DIR`, as environment variables are shared by every test
+// in this binary.
+#[test]
+fn input_dir_takes_precedence_over_bundled_input() {
+    let dir = env::temp_dir().join(format!("aoc_input_test_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("day1.txt"), "1\n").unwrap();
+
+    env::set_var(INPUT_DIR_VAR, &dir);
+
+    let from_dir = input::resolve(1, None);
+    let candidates = input::candidates(2, None);
+
+    env::remove_var(INPUT_DIR_VAR);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(from_dir.unwrap(), dir.join("day1.txt"));
+    assert_eq!(candidates.first().unwrap().0, InputSource::EnvDir);
+    assert_eq!(
+        candidates.last().unwrap(),
+        &(InputSource::Bundled, input::bundled_path(2))
+    );
+}
@@ -0,0 +1,134 @@
+use adventofcode_2022::{
+    find_day,
+    parse::{ParseError, ParseErrorKind},
+    Part,
+};
+use pretty_assertions::assert_str_eq;
+
+/// Solves part 1 of a day with a malformed input, returning the parse error it fails with.
+fn parse_error(day_number: u8, input: &str) -> ParseError {
+    let err = find_day(day_number)
+        .unwrap()
+        .solve(input, Part::One)
+        .expect_err("The malformed input should fail to parse.");
+
+    *err.downcast::<ParseError>()
+        .expect("The input should fail with a `ParseError`.")
+}
+
+#[test]
+fn renders_like_rustc() {
+    let source = "R 4\nU x\n";
+
+    let err = ParseError::new(ParseErrorKind::InvalidNumber, source, &source[6..7]).with_day(9);
+
+    assert_str_eq!(
+        err.to_string(),
+        "invalid number\n --> day 9, line 2:3\n  |\n2 | U x\n  |   ^"
+    );
+}
+
+#[test]
+fn errors_are_located_within_the_whole_input() {
+    let err = parse_error(4, "2-4,6-8\n2-3,4-5\n5-7,7-x9\n");
+
+    assert_eq!(err.day, Some(4));
+    assert_eq!(err.line, 3);
+    assert_eq!(err.columns, 6..8);
+    assert_eq!(err.text, "x9");
+    assert_eq!(err.kind, ParseErrorKind::InvalidNumber);
+}
+
+#[test]
+fn errors_in_later_blocks_keep_their_line() {
+    let input = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65
+  Operation: new = old / 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+";
+
+    let err = parse_error(11, input);
+
+    assert_eq!(err.line, 10);
+    assert_eq!(err.source_line, "  Operation: new = old / 6");
+    assert_eq!(err.text, "/");
+    assert_eq!(err.columns, 23..24);
+}
+
+#[test]
+fn cycles_are_reported_at_a_monkey_within_them() {
+    let err = parse_error(
+        21,
+        "root: aaaa + bbbb\naaaa: bbbb * cccc\nbbbb: aaaa - cccc\ncccc: 2\n",
+    );
+
+    assert!([2, 3].contains(&err.line));
+    assert_eq!(err.columns, 0..4);
+}
+
+#[test]
+fn a_missing_starting_valve_is_reported_past_the_end() {
+    let input = "Valve BB has flow rate=13; tunnel leads to valve CC\n\
+                 Valve CC has flow rate=2; tunnel leads to valve BB\n";
+
+    let err = parse_error(16, input);
+
+    assert_eq!(err.line, 3);
+    assert_eq!(err.kind, ParseErrorKind::Missing("the starting valve `AA`"));
+
+    assert_eq!(
+        parse_error(16, "").kind,
+        ParseErrorKind::Missing("the starting valve `AA`")
+    );
+}
+
+#[test]
+fn moves_are_checked_against_the_stacks_drawn() {
+    let drawing = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n";
+
+    let err = parse_error(
+        5,
+        &format!("{drawing}move 1 from 2 to 1\nmove 1 from 9 to 1\n"),
+    );
+
+    assert_eq!(err.line, 7);
+    assert_eq!(err.text, "9");
+    assert_eq!(err.columns, 12..13);
+
+    // Stack 3 only holds one crate.
+    let err = parse_error(
+        5,
+        &format!("{drawing}move 1 from 2 to 1\nmove 2 from 3 to 1\n"),
+    );
+
+    assert_eq!(err.line, 7);
+    assert_eq!(err.text, "2");
+    assert_eq!(err.columns, 5..6);
+}
+
+#[test]
+fn rucksacks_only_hold_letters_split_evenly() {
+    let err = parse_error(3, "vJrwpWtwJgWr\njqHRNq1zz\n");
+
+    assert_eq!(err.line, 2);
+    assert_eq!(err.text, "1");
+    assert_eq!(err.columns, 6..7);
+
+    let err = parse_error(3, "vJrwpWtwJgWr\nvJrwpWé\n");
+
+    assert_eq!((err.line, err.text.as_str()), (2, "é"));
+
+    let err = parse_error(3, "vJrwpWtwJgWr\nabc\n");
+
+    assert_eq!((err.line, err.text.as_str()), (2, "abc"));
+}
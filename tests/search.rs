@@ -0,0 +1,107 @@
+use adventofcode_2022::{
+    grid::Grid,
+    search::{astar, bfs, dijkstra},
+};
+
+/// A maze where `#` is a wall, and the digits are the cost of stepping onto a square.
+fn maze() -> Grid<char> {
+    "\
+S1#11
+19#91
+11111"
+        .parse()
+        .unwrap()
+}
+
+type Position = (usize, usize);
+
+fn open_neighbors(maze: &Grid<char>) -> impl Fn(&Position) -> Vec<Position> + '_ {
+    |&position| {
+        maze.neighbors4(position)
+            .filter(|&neighbor| maze[neighbor] != '#')
+            .collect()
+    }
+}
+
+fn weighted_neighbors(maze: &Grid<char>) -> impl Fn(&Position) -> Vec<(Position, u32)> + '_ {
+    |&position| {
+        maze.neighbors4(position)
+            .filter_map(|neighbor| Some((neighbor, maze[neighbor].to_digit(10)?)))
+            .collect()
+    }
+}
+
+#[test]
+fn bfs_finds_the_shortest_path() {
+    let maze = maze();
+
+    let result = bfs(&open_neighbors(&maze), [(0, 0)], |&position| {
+        position == (4, 0)
+    });
+
+    assert_eq!(result.goal(), Some(&(4, 0)));
+    assert_eq!(result.goal_cost(), Some(8));
+    let path = result.path().unwrap();
+
+    // There are several equally short routes, which all start and end in the same places.
+    assert_eq!(path.len(), 9);
+    assert_eq!((path[0], path[8]), ((0, 0), (4, 0)));
+}
+
+#[test]
+fn bfs_without_a_goal_maps_every_reachable_node() {
+    let maze = maze();
+
+    let result = bfs(&open_neighbors(&maze), [(0, 0)], |_| false);
+
+    assert_eq!(result.goal(), None);
+    assert_eq!(result.costs().len(), 13);
+    assert_eq!(result.cost(&(3, 1)), Some(6));
+    assert_eq!(result.cost(&(2, 0)), None);
+    assert_eq!(result.path_to(&(2, 0)), None);
+}
+
+#[test]
+fn searches_from_several_starts_at_once() {
+    let maze = maze();
+
+    let result = bfs(&open_neighbors(&maze), [(0, 0), (4, 0)], |_| false);
+
+    assert_eq!(result.cost(&(3, 1)), Some(2));
+    assert_eq!(result.path_to(&(3, 1)).unwrap()[0], (4, 0));
+    assert_eq!(result.cost(&(1, 1)), Some(2));
+}
+
+#[test]
+fn dijkstra_avoids_expensive_steps() {
+    let maze = maze();
+
+    let goals = [(3, 0), (4, 0)];
+
+    let result = dijkstra(&weighted_neighbors(&maze), [(0, 0)], |position| {
+        goals.contains(position)
+    });
+
+    // Through the 9 costs 1 + 1 + 1 + 9 + 1 = 13, around it costs 1 * 8 = 8.
+    assert_eq!(result.goal(), Some(&(4, 0)));
+    assert_eq!(result.goal_cost(), Some(8));
+    assert!(!result.path().unwrap().contains(&(3, 1)));
+}
+
+#[test]
+fn astar_agrees_with_dijkstra() {
+    let maze = maze();
+
+    let goal = (4, 0);
+    let manhattan = |&(x, y): &Position| (x.abs_diff(goal.0) + y.abs_diff(goal.1)) as u32;
+
+    let result = astar(
+        &weighted_neighbors(&maze),
+        [(0, 0)],
+        |&position| position == goal,
+        manhattan,
+    );
+
+    assert_eq!(result.goal_cost(), Some(8));
+    assert_eq!(result.path().unwrap().len(), 9);
+}
@@ -0,0 +1,68 @@
+use adventofcode_2022::snafu::Snafu;
+
+/// Decimal numbers and their SNAFU equivalents, from the puzzle.
+const EXAMPLES: [(i64, &str); 10] = [
+    (1, "1"),
+    (2, "2"),
+    (3, "1="),
+    (4, "1-"),
+    (5, "10"),
+    (10, "20"),
+    (20, "1-0"),
+    (2022, "1=11-2"),
+    (12345, "1-0---0"),
+    (314159265, "1121-1110-1=0"),
+];
+
+#[test]
+fn converts_between_decimal_and_snafu() {
+    for (decimal, snafu) in EXAMPLES {
+        assert_eq!(Snafu::from(decimal).to_string(), snafu);
+        assert_eq!(
+            i64::try_from(snafu.parse::<Snafu>().unwrap()).unwrap(),
+            decimal
+        );
+    }
+
+    assert_eq!(Snafu::zero().to_string(), "0");
+    assert_eq!(Snafu::from(-3_i64).to_string(), "-2");
+    assert_eq!("00=".parse::<Snafu>().unwrap(), Snafu::from(-2_i64));
+}
+
+#[test]
+fn converts_extreme_values() {
+    for value in [i128::MIN, i128::MAX] {
+        assert_eq!(i128::try_from(Snafu::from(value)).unwrap(), value);
+    }
+
+    assert!(i64::try_from(Snafu::from(i128::from(i64::MAX) + 1)).is_err());
+}
+
+#[test]
+fn adds_without_overflowing() {
+    let sum: Snafu = EXAMPLES
+        .iter()
+        .map(|&(decimal, _)| Snafu::from(decimal))
+        .sum();
+
+    assert_eq!(
+        i64::try_from(sum).unwrap(),
+        EXAMPLES.iter().map(|&(decimal, _)| decimal).sum::<i64>()
+    );
+
+    let max = Snafu::from(i128::MAX);
+    let doubled = &max + &max;
+
+    assert!(i128::try_from(&doubled).is_err());
+    assert_eq!(
+        doubled + Snafu::from(i128::MIN) + Snafu::from(i128::MIN),
+        Snafu::from(-2_i64)
+    );
+}
+
+#[test]
+fn rejects_other_digits() {
+    let err = "1=3-".parse::<Snafu>().unwrap_err();
+
+    assert_eq!(err.columns, 2..3);
+}